@@ -0,0 +1,22 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use polars_arrow::array::MutablePrimitiveArray;
+
+fn bench_fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill");
+    for size in [1 << 10, 1 << 16, 1 << 20] {
+        let mut array = MutablePrimitiveArray::<u32>::from_vec(vec![0; size]);
+
+        group.bench_with_input(BenchmarkId::new("slice_fill", size), &size, |b, _| {
+            b.iter(|| array.values_mut_slice().fill(black_box(7)))
+        });
+        group.bench_with_input(BenchmarkId::new("simd_fill", size), &size, |b, _| {
+            b.iter(|| array.simd_fill(black_box(7)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_fill);
+criterion_main!(benches);
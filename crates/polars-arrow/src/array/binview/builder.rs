@@ -34,6 +34,8 @@ pub struct BinaryViewArrayGenericBuilder<V: ViewType + ?Sized> {
     total_bytes_len: usize,
     /// Total bytes in the buffer set (excluding remaining capacity).
     total_buffer_len: usize,
+    /// Width of the values pushed with `push_fixed_width`, set by the first of them.
+    fixed_width: Option<usize>,
     view_type: PhantomData<V>,
 }
 
@@ -52,6 +54,7 @@ impl<V: ViewType + ?Sized> BinaryViewArrayGenericBuilder<V> {
             validity: OptBitmapBuilder::default(),
             total_bytes_len: 0,
             total_buffer_len: 0,
+            fixed_width: None,
             view_type: PhantomData,
         }
     }
@@ -92,12 +95,7 @@ impl<V: ViewType + ?Sized> BinaryViewArrayGenericBuilder<V> {
         self.total_bytes_len += bytes.len();
         unsafe {
             let view = if bytes.len() > View::MAX_INLINE_SIZE as usize {
-                self.reserve_active_buffer(bytes.len());
-
-                let offset = self.active_buffer.len() as u32; // Ensured no overflow by reserve_active_buffer.
-                self.active_buffer.extend_from_slice(bytes);
-                self.total_buffer_len += bytes.len();
-                View::new_noninline_unchecked(bytes, self.active_buffer_idx, offset)
+                self.push_to_active_buffer(bytes)
             } else {
                 View::new_inline_unchecked(bytes)
             };
@@ -105,6 +103,20 @@ impl<V: ViewType + ?Sized> BinaryViewArrayGenericBuilder<V> {
         }
     }
 
+    /// Copy `bytes` into the active buffer and return the (non-inline) view of them.
+    ///
+    /// # Safety
+    /// `bytes` must be longer than `View::MAX_INLINE_SIZE`.
+    #[inline]
+    unsafe fn push_to_active_buffer(&mut self, bytes: &[u8]) -> View {
+        self.reserve_active_buffer(bytes.len());
+
+        let offset = self.active_buffer.len() as u32; // Ensured no overflow by reserve_active_buffer.
+        self.active_buffer.extend_from_slice(bytes);
+        self.total_buffer_len += bytes.len();
+        unsafe { View::new_noninline_unchecked(bytes, self.active_buffer_idx, offset) }
+    }
+
     /// # Safety
     /// The view must be inline.
     pub unsafe fn push_inline_view_ignore_validity(&mut self, view: View) {
@@ -213,6 +225,66 @@ impl<V: ViewType + ?Sized> BinaryViewArrayGenericBuilder<V> {
     }
 }
 
+impl BinaryViewArrayGenericBuilder<[u8]> {
+    /// Push a value of which all non-null values pushed to this builder have the same width.
+    ///
+    /// Whether the values are stored inline is decided once, from the width of the first
+    /// non-null value. Nulls are pushed as an empty inline view.
+    ///
+    /// # Panics
+    /// Panics if `value` has a different width than the first non-null value.
+    #[inline]
+    pub fn push_fixed_width(&mut self, value: Option<&[u8]>) -> &mut Self {
+        match value {
+            Some(bytes) => {
+                let width = *self.fixed_width.get_or_insert(bytes.len());
+                assert_eq!(
+                    bytes.len(),
+                    width,
+                    "push_fixed_width requires all values to have the same width"
+                );
+                self.total_bytes_len += width;
+                let view = if width > View::MAX_INLINE_SIZE as usize {
+                    // SAFETY: the width was checked above.
+                    unsafe { self.push_to_active_buffer(bytes) }
+                } else {
+                    // SAFETY: the width was checked above.
+                    unsafe { View::new_inline_unchecked(bytes) }
+                };
+                self.views.push(view);
+                self.validity.extend_constant(1, true);
+            },
+            None => {
+                self.views.push(View::default());
+                self.validity.extend_constant(1, false);
+            },
+        }
+        self
+    }
+
+    /// Push a value of `N <= 12` bytes, which is always stored inline in the view.
+    ///
+    /// The view is written directly, without checking at runtime whether the value fits inline
+    /// and without branching on whether the value is null. Nulls are pushed as an empty view.
+    #[inline]
+    pub fn push_fixed_width_unchecked_inline<const N: usize>(
+        &mut self,
+        value: Option<[u8; N]>,
+    ) -> &mut Self {
+        const { assert!(N <= View::MAX_INLINE_SIZE as usize) };
+
+        let is_valid = value.is_some();
+        let bytes = value.unwrap_or([0; N]);
+        let len = N * is_valid as usize;
+        // SAFETY: `len <= N <= View::MAX_INLINE_SIZE`, so the view is inline.
+        unsafe {
+            self.push_inline_view_ignore_validity(View::new_inline_unchecked(&bytes[..len]));
+        }
+        self.validity.extend_constant(1, is_valid);
+        self
+    }
+}
+
 impl<V: ViewType + ?Sized> StaticArrayBuilder for BinaryViewArrayGenericBuilder<V> {
     type Array = BinaryViewArrayGeneric<V>;
 
@@ -268,6 +340,7 @@ impl<V: ViewType + ?Sized> StaticArrayBuilder for BinaryViewArrayGenericBuilder<
         self.total_buffer_len = 0;
         self.total_bytes_len = 0;
         self.active_buffer_idx = 0;
+        self.fixed_width = None;
         self.stolen_buffers.clear();
         self.last_buffer_set_stolen_from = None;
         out
@@ -9,6 +9,10 @@ mod iterator;
 mod mutable;
 #[cfg(feature = "proptest")]
 pub mod proptest;
+#[cfg(feature = "regex")]
+mod regex;
+#[cfg(feature = "protobuf")]
+mod varint;
 mod view;
 
 use std::any::Any;
@@ -574,6 +578,23 @@ impl BinaryViewArray {
         unsafe { Ok(self.to_utf8view_unchecked()) }
     }
 
+    /// Convert [`BinaryViewArray`] to [`Utf8ViewArray`], replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// If all values are valid UTF-8 the buffers are shared without copying.
+    pub fn parse_utf8_lossy(&self) -> Utf8ViewArray {
+        if self.validate_utf8().is_ok() {
+            // SAFETY: we just validated the UTF-8.
+            return unsafe { self.to_utf8view_unchecked() };
+        }
+
+        let mut mutable = MutableBinaryViewArray::<str>::with_capacity(self.len());
+        for opt_v in self.iter() {
+            mutable.push(opt_v.map(String::from_utf8_lossy));
+        }
+        mutable.freeze()
+    }
+
     /// Convert [`BinaryViewArray`] to [`Utf8ViewArray`] without checking UTF-8.
     ///
     /// # Safety
@@ -0,0 +1,47 @@
+use polars_error::PolarsResult;
+use regex::bytes::Regex;
+
+use super::BinaryViewArray;
+use crate::array::{Array, FixedSizeListArray, ListArray, PrimitiveArray};
+use crate::datatypes::ArrowDataType;
+use crate::offset::Offsets;
+
+impl BinaryViewArray {
+    /// Find all non-overlapping matches of `pattern` in every element.
+    ///
+    /// Every element is mapped to a list of `[start, end)` byte offset pairs, stored as a
+    /// [`FixedSizeListArray`] of size 2. Null elements result in null lists.
+    pub fn regexp_find_all(&self, pattern: &str) -> PolarsResult<ListArray<i64>> {
+        let re = Regex::new(pattern)?;
+
+        let mut positions: Vec<i64> = Vec::new();
+        let mut offsets = Offsets::<i64>::with_capacity(self.len());
+        for opt_v in self.iter() {
+            let mut n_matches = 0;
+            if let Some(v) = opt_v {
+                for m in re.find_iter(v) {
+                    positions.push(m.start() as i64);
+                    positions.push(m.end() as i64);
+                    n_matches += 1;
+                }
+            }
+            offsets.try_push(n_matches)?;
+        }
+
+        let n_pairs = positions.len() / 2;
+        let pair_dtype = FixedSizeListArray::default_datatype(ArrowDataType::Int64, 2);
+        let pairs = FixedSizeListArray::new(
+            pair_dtype.clone(),
+            n_pairs,
+            PrimitiveArray::from_vec(positions).boxed(),
+            None,
+        );
+
+        ListArray::try_new(
+            ListArray::<i64>::default_datatype(pair_dtype),
+            offsets.into(),
+            pairs.boxed(),
+            self.validity().cloned(),
+        )
+    }
+}
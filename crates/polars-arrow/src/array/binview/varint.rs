@@ -0,0 +1,76 @@
+use polars_error::{PolarsResult, polars_bail};
+
+use super::BinaryViewArray;
+use crate::array::{Array, Int64Array};
+use crate::datatypes::ArrowDataType;
+
+/// The maximum number of bytes of a base-128 varint encoding 64 bits.
+const MAX_VARINT_LEN: usize = 10;
+
+/// Decode the base-128 varint at the start of `bytes`.
+fn decode(bytes: &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (i, &byte) in bytes.iter().take(MAX_VARINT_LEN).enumerate() {
+        let low = (byte & 0x7F) as u64;
+        // The tenth byte may only contribute the most significant bit.
+        if i == MAX_VARINT_LEN - 1 && low > 1 {
+            return None;
+        }
+        value |= low << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+impl BinaryViewArray {
+    /// Decode the base-128 varint at the start of every element.
+    ///
+    /// The varints use the protobuf encoding of `int64`, so values of 64 bits with the most
+    /// significant bit set are negative. Trailing bytes after the first varint are ignored.
+    /// Null elements result in nulls.
+    ///
+    /// # Errors
+    /// Errors if an element doesn't start with a valid varint, e.g. if it is truncated.
+    pub fn decode_varint(&self) -> PolarsResult<Int64Array> {
+        let mut values = Vec::with_capacity(self.len());
+        for (i, opt_v) in self.iter().enumerate() {
+            let v = match opt_v {
+                None => 0,
+                Some(bytes) => match decode(bytes) {
+                    Some(v) => v as i64,
+                    None => polars_bail!(ComputeError: "invalid varint at index {i}"),
+                },
+            };
+            values.push(v);
+        }
+        Ok(Int64Array::new(
+            ArrowDataType::Int64,
+            values.into(),
+            self.validity().cloned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_varint() {
+        let array = BinaryViewArray::from_slice([
+            Some(&[0x00][..]),
+            Some(&[0x96, 0x01, 0xFF][..]),
+            None,
+            Some(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..]),
+        ]);
+        let out = array.decode_varint().unwrap();
+        assert_eq!(out, Int64Array::from([Some(0), Some(150), None, Some(-1)]));
+
+        let truncated = BinaryViewArray::from_slice([Some(&[0x96][..])]);
+        assert!(truncated.decode_varint().is_err());
+        let too_long = BinaryViewArray::from_slice([Some(&[0xFF; 10][..])]);
+        assert!(too_long.decode_varint().is_err());
+    }
+}
@@ -193,6 +193,11 @@ pub trait ArrayBuilder: ArrayBuilderBoxedHelper + Send {
     /// Returns the length of this builder (so far).
     fn len(&self) -> usize;
 
+    /// Returns whether this builder is empty (so far).
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Extend this builder with the given number of null elements.
     fn extend_nulls(&mut self, length: usize);
 
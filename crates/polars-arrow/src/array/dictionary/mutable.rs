@@ -4,7 +4,7 @@ use std::sync::Arc;
 use polars_error::PolarsResult;
 
 use super::value_map::ValueMap;
-use super::{DictionaryArray, DictionaryKey};
+use super::{DictValue, DictionaryArray, DictionaryKey};
 use crate::array::indexable::{AsIndexed, Indexable};
 use crate::array::primitive::MutablePrimitiveArray;
 use crate::array::{Array, MutableArray, TryExtend, TryPush};
@@ -142,6 +142,41 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
     }
 }
 
+impl<K: DictionaryKey, M: MutableArray + Indexable> MutableDictionaryArray<K, M>
+where
+    M::Type: Eq + Hash,
+{
+    /// Append the values of `other` to this array.
+    ///
+    /// The values of `other`'s dictionary are added to this dictionary if they are not in it yet,
+    /// and `other`'s keys are remapped to the keys of this dictionary. `V` is the type of the
+    /// values of `other`'s dictionary.
+    pub fn merge_from<'a, V>(&mut self, other: &'a DictionaryArray<K>) -> PolarsResult<()>
+    where
+        V: DictValue + 'a,
+        V::IterValue<'a>: AsIndexed<M>,
+        M: TryPush<Option<V::IterValue<'a>>>,
+    {
+        let values = V::downcast_values(other.values().as_ref())?;
+        let remap = (0..other.values().len())
+            .map(|i| {
+                // SAFETY: `i` is in bounds.
+                let value = unsafe { values.get_unchecked(i) };
+                self.map
+                    .try_push_valid(value, |arr, v| arr.try_push(Some(v)))
+            })
+            .collect::<PolarsResult<Vec<K>>>()?;
+
+        self.keys.reserve(other.len());
+        for key in other.keys().iter() {
+            // SAFETY: the keys of a `DictionaryArray` are in bounds of its values.
+            let key = key.map(|k| unsafe { *remap.get_unchecked(k.as_usize()) });
+            self.keys.push(key);
+        }
+        Ok(())
+    }
+}
+
 impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictionaryArray<K, M> {
     fn len(&self) -> usize {
         self.keys.len()
@@ -1,5 +1,5 @@
 use super::specification::try_check_offsets_bounds;
-use super::{Array, Splitable, new_empty_array};
+use super::{Array, PrimitiveArray, Splitable, new_empty_array};
 use crate::bitmap::Bitmap;
 use crate::datatypes::{ArrowDataType, Field};
 use crate::offset::{Offset, Offsets, OffsetsBuffer};
@@ -181,6 +181,14 @@ impl<O: Offset> ListArray<O> {
         &self.offsets
     }
 
+    /// The offsets as a [`PrimitiveArray`] of length `self.len() + 1`.
+    ///
+    /// This is zero-copy: the array shares the offsets buffer. The length of element `i` is the
+    /// difference between the offsets at `i + 1` and `i`, also for null elements.
+    pub fn element_offsets(&self) -> PrimitiveArray<O> {
+        PrimitiveArray::new(O::PRIMITIVE.into(), self.offsets.buffer().clone(), None)
+    }
+
     /// The values.
     #[inline]
     pub fn values(&self) -> &Box<dyn Array> {
@@ -238,6 +246,12 @@ impl<O: Offset> Array for ListArray<O> {
         self.validity.as_ref()
     }
 
+    fn physical_null_count(&self) -> usize {
+        let start = self.offsets.first().to_usize();
+        let end = self.offsets.last().to_usize();
+        self.null_count() + self.values.sliced(start, end - start).physical_null_count()
+    }
+
     #[inline]
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
         Box::new(self.clone().with_validity(validity))
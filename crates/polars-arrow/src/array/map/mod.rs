@@ -188,6 +188,12 @@ impl Array for MapArray {
         self.validity.as_ref()
     }
 
+    fn physical_null_count(&self) -> usize {
+        let start = *self.offsets.first() as usize;
+        let end = *self.offsets.last() as usize;
+        self.null_count() + self.field.sliced(start, end - start).physical_null_count()
+    }
+
     #[inline]
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
         Box::new(self.clone().with_validity(validity))
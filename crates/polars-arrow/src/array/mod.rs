@@ -106,6 +106,14 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
         self.null_count() > 0
     }
 
+    /// The number of null slots on this [`Array`] and, recursively, on its child arrays.
+    ///
+    /// For non-nested arrays this is equal to [`Array::null_count`]. Only the child values in
+    /// range of this [`Array`] are counted.
+    fn physical_null_count(&self) -> usize {
+        self.null_count()
+    }
+
     /// Returns whether slot `i` is null.
     /// # Panic
     /// Panics iff `i >= self.len()`.
@@ -646,6 +654,29 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+/// Returns a copy of `array` whose validity is its own validity AND-ed with `extra_validity`.
+///
+/// An array without validity is treated as all-valid.
+/// # Panics
+/// Panics iff `extra_validity.len() != array.len()` or if `array` is a [`UnionArray`], which
+/// can't have a validity.
+pub fn merge_validity(array: &dyn Array, extra_validity: &Bitmap) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType;
+    assert_eq!(
+        extra_validity.len(),
+        array.len(),
+        "validity must be equal to the array's length"
+    );
+    let validity =
+        crate::compute::utils::combine_validities_and(array.validity(), Some(extra_validity));
+
+    // A `NullArray` is null regardless of its validity.
+    if array.dtype().to_physical_type() == PhysicalType::Null {
+        return array.to_boxed();
+    }
+    array.with_validity(validity)
+}
+
 // see https://users.rust-lang.org/t/generic-for-dyn-a-or-box-dyn-a-or-arc-dyn-a/69430/3
 // for details
 impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
@@ -664,6 +695,7 @@ mod list;
 pub use list::LIST_VALUES_NAME;
 mod map;
 mod null;
+mod owned;
 mod primitive;
 pub mod specification;
 mod static_array;
@@ -706,6 +738,7 @@ pub use iterator::ArrayValuesIter;
 pub use list::{ListArray, ListArrayBuilder, ListValuesIter, MutableListArray};
 pub use map::MapArray;
 pub use null::{MutableNullArray, NullArray, NullArrayBuilder};
+pub use owned::OwnedArray;
 use polars_error::PolarsResult;
 pub use primitive::*;
 pub use static_array::{ParameterFreeDtypeStaticArray, StaticArray};
@@ -0,0 +1,78 @@
+use polars_utils::pl_str::PlSmallStr;
+
+use super::Array;
+use crate::datatypes::{ArrowDataType, Field};
+
+/// A named, owned [`Array`].
+#[derive(Debug, Clone)]
+pub struct OwnedArray {
+    name: PlSmallStr,
+    array: Box<dyn Array>,
+}
+
+impl OwnedArray {
+    pub fn new(name: PlSmallStr, array: Box<dyn Array>) -> Self {
+        Self { name, array }
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn array(&self) -> &dyn Array {
+        self.array.as_ref()
+    }
+
+    pub fn dtype(&self) -> &ArrowDataType {
+        self.array.dtype()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slices the array.
+    ///
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    pub fn slice(&mut self, offset: usize, length: usize) {
+        self.array.slice(offset, length)
+    }
+
+    /// Returns a new [`OwnedArray`] with the array sliced.
+    ///
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn sliced(mut self, offset: usize, length: usize) -> Self {
+        self.slice(offset, length);
+        self
+    }
+
+    /// The [`Field`] describing this array.
+    pub fn field(&self) -> Field {
+        Field::new(self.name.clone(), self.dtype().clone(), true)
+    }
+
+    pub fn into_inner(self) -> (PlSmallStr, Box<dyn Array>) {
+        (self.name, self.array)
+    }
+}
+
+impl From<(PlSmallStr, Box<dyn Array>)> for OwnedArray {
+    fn from((name, array): (PlSmallStr, Box<dyn Array>)) -> Self {
+        Self::new(name, array)
+    }
+}
+
+impl From<OwnedArray> for (PlSmallStr, Box<dyn Array>) {
+    fn from(value: OwnedArray) -> Self {
+        value.into_inner()
+    }
+}
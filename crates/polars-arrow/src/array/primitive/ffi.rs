@@ -42,6 +42,7 @@ unsafe impl<T: NativeType> ToFfi for PrimitiveArray<T> {
             dtype: self.dtype.clone(),
             validity,
             values: self.values.clone(),
+            validity_shape: self.validity_shape,
         }
     }
 }
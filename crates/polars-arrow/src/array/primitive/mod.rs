@@ -4,8 +4,8 @@ use either::Either;
 
 use super::{Array, Splitable};
 use crate::array::iterator::NonNullValuesIter;
-use crate::bitmap::Bitmap;
 use crate::bitmap::utils::{BitmapIter, ZipValidity};
+use crate::bitmap::{Bitmap, ValidityShape};
 use crate::buffer::Buffer;
 use crate::datatypes::*;
 use crate::trusted_len::TrustedLen;
@@ -22,6 +22,7 @@ mod mutable;
 pub use mutable::*;
 mod builder;
 pub use builder::*;
+mod zip;
 use polars_error::{PolarsResult, polars_bail};
 use polars_utils::index::{Bounded, Indexable, NullCount};
 use polars_utils::slice::SliceAble;
@@ -36,6 +37,9 @@ use polars_utils::slice::SliceAble;
 /// * the third is the immutable validity (whether a value is null or not as a bitmap).
 ///
 /// The size of this struct is `O(1)`, as all data is stored behind an [`std::sync::Arc`].
+///
+/// Next to the validity the array keeps its [`ValidityShape`], so arrays without nulls or with a
+/// single run of nulls at the start can answer null queries in `O(1)`.
 /// # Example
 /// ```
 /// use polars_arrow::array::PrimitiveArray;
@@ -56,6 +60,8 @@ pub struct PrimitiveArray<T: NativeType> {
     dtype: ArrowDataType,
     values: Buffer<T>,
     validity: Option<Bitmap>,
+    /// The shape of `validity`, as far as it is known without scanning the bitmap.
+    validity_shape: ValidityShape,
 }
 
 pub(super) fn check<T: NativeType>(
@@ -89,6 +95,7 @@ impl<T: NativeType> PrimitiveArray<T> {
     ) -> PolarsResult<Self> {
         check(&dtype, &values, validity.as_ref().map(|v| v.len()))?;
         Ok(Self {
+            validity_shape: ValidityShape::from_validity_lazy(validity.as_ref()),
             dtype,
             values,
             validity,
@@ -107,6 +114,7 @@ impl<T: NativeType> PrimitiveArray<T> {
         }
 
         Self {
+            validity_shape: ValidityShape::from_validity_lazy(validity.as_ref()),
             dtype,
             values,
             validity,
@@ -143,6 +151,7 @@ impl<T: NativeType> PrimitiveArray<T> {
             dtype,
             values: self.values,
             validity: self.validity,
+            validity_shape: self.validity_shape,
         }
     }
 
@@ -196,6 +205,36 @@ impl<T: NativeType> PrimitiveArray<T> {
         self.validity.as_ref()
     }
 
+    /// Returns the [`ValidityShape`] of the validity.
+    ///
+    /// This is `O(1)`. Arrays created without a validity or through [`PrimitiveArray::new_null`]
+    /// and [`PrimitiveArray::with_validity_shape`] keep their shape when sliced. Other validities
+    /// are only detected as [`ValidityShape::NullPrefix`] if their null count is cached and equal
+    /// to their length.
+    #[inline]
+    pub fn validity_shape(&self) -> &ValidityShape {
+        &self.validity_shape
+    }
+
+    /// Returns this array with the validity described by `shape`.
+    ///
+    /// # Panics
+    /// Panics iff `shape` is [`ValidityShape::ArbitraryNulls`], which doesn't describe a bitmap,
+    /// or [`ValidityShape::NullPrefix`] with `n > self.len()`.
+    #[must_use]
+    pub fn with_validity_shape(mut self, shape: ValidityShape) -> Self {
+        if matches!(&shape, ValidityShape::NullPrefix { n } if *n > self.len()) {
+            panic!("null prefix may not exceed the array's length")
+        }
+        let shape = match shape {
+            ValidityShape::NullPrefix { n: 0 } => ValidityShape::AllValid,
+            shape => shape,
+        };
+        self.set_validity(shape.into_validity(self.len()));
+        self.validity_shape = shape;
+        self
+    }
+
     /// Returns the arrays' [`ArrowDataType`].
     #[inline]
     pub fn dtype(&self) -> &ArrowDataType {
@@ -255,18 +294,59 @@ impl<T: NativeType> PrimitiveArray<T> {
     /// The caller must ensure that `offset + length <= self.len()`.
     #[inline]
     pub unsafe fn slice_unchecked(&mut self, offset: usize, length: usize) {
-        self.validity = self
-            .validity
-            .take()
-            .map(|bitmap| bitmap.sliced_unchecked(offset, length))
-            .filter(|bitmap| bitmap.unset_bits() > 0);
+        let validity = self.validity.take();
+        (self.validity, self.validity_shape) = match self.validity_shape {
+            ValidityShape::AllValid => (None, ValidityShape::AllValid),
+            shape @ ValidityShape::NullPrefix { .. } => match shape.sliced(offset, length) {
+                ValidityShape::NullPrefix { n } => (
+                    validity.map(|bitmap| bitmap.sliced_unchecked(offset, length)),
+                    ValidityShape::NullPrefix { n },
+                ),
+                _ => (None, ValidityShape::AllValid),
+            },
+            ValidityShape::ArbitraryNulls => {
+                let validity = validity
+                    .map(|bitmap| bitmap.sliced_unchecked(offset, length))
+                    .filter(|bitmap| bitmap.unset_bits() > 0);
+                let shape = ValidityShape::from_validity_lazy(validity.as_ref());
+                (validity, shape)
+            },
+        };
         self.values.slice_unchecked(offset, length);
     }
 
     impl_sliced!();
-    impl_mut_validity!();
     impl_into_array!();
 
+    /// Returns this array with a new validity.
+    /// # Panic
+    /// Panics iff `validity.len() != self.len()`.
+    #[must_use]
+    #[inline]
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity of this array.
+    /// # Panics
+    /// This function panics iff `values.len() != self.len()`.
+    #[inline]
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity must be equal to the array's length")
+        }
+        self.validity_shape = ValidityShape::from_validity_lazy(validity.as_ref());
+        self.validity = validity;
+    }
+
+    /// Takes the validity of this array, leaving it without a validity mask.
+    #[inline]
+    pub fn take_validity(&mut self) -> Option<Bitmap> {
+        self.validity_shape = ValidityShape::AllValid;
+        self.validity.take()
+    }
+
     /// Returns this [`PrimitiveArray`] with new values.
     /// # Panics
     /// This function panics iff `values.len() != self.len()`.
@@ -311,6 +391,7 @@ impl<T: NativeType> PrimitiveArray<T> {
             dtype,
             values,
             validity,
+            ..
         } = self;
         (dtype, values, validity)
     }
@@ -337,6 +418,7 @@ impl<T: NativeType> PrimitiveArray<T> {
         validity: Option<Bitmap>,
     ) -> Self {
         Self {
+            validity_shape: ValidityShape::from_validity_lazy(validity.as_ref()),
             dtype,
             values,
             validity,
@@ -490,6 +572,30 @@ impl<T: NativeType> Array for PrimitiveArray<T> {
         self.validity.as_ref()
     }
 
+    #[inline]
+    fn null_count(&self) -> usize {
+        match &self.validity_shape {
+            ValidityShape::AllValid => 0,
+            ValidityShape::NullPrefix { n } => *n,
+            ValidityShape::ArbitraryNulls => self
+                .validity
+                .as_ref()
+                .map_or(0, |bitmap| bitmap.unset_bits()),
+        }
+    }
+
+    #[inline]
+    unsafe fn is_null_unchecked(&self, i: usize) -> bool {
+        match &self.validity_shape {
+            ValidityShape::AllValid => false,
+            ValidityShape::NullPrefix { n } => i < *n,
+            ValidityShape::ArbitraryNulls => self
+                .validity
+                .as_ref()
+                .is_some_and(|bitmap| !unsafe { bitmap.get_bit_unchecked(i) }),
+        }
+    }
+
     #[inline]
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
         Box::new(self.clone().with_validity(validity))
@@ -505,17 +611,29 @@ impl<T: NativeType> Splitable for PrimitiveArray<T> {
     unsafe fn _split_at_unchecked(&self, offset: usize) -> (Self, Self) {
         let (lhs_values, rhs_values) = unsafe { self.values.split_at_unchecked(offset) };
         let (lhs_validity, rhs_validity) = unsafe { self.validity.split_at_unchecked(offset) };
+        let (lhs_shape, rhs_shape) = match self.validity_shape {
+            ValidityShape::ArbitraryNulls => (
+                ValidityShape::from_validity_lazy(lhs_validity.as_ref()),
+                ValidityShape::from_validity_lazy(rhs_validity.as_ref()),
+            ),
+            shape => (
+                shape.sliced(0, offset),
+                shape.sliced(offset, self.len() - offset),
+            ),
+        };
 
         (
             Self {
                 dtype: self.dtype.clone(),
                 values: lhs_values,
                 validity: lhs_validity,
+                validity_shape: lhs_shape,
             },
             Self {
                 dtype: self.dtype.clone(),
                 values: rhs_values,
                 validity: rhs_validity,
+                validity_shape: rhs_shape,
             },
         )
     }
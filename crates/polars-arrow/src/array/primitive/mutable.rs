@@ -323,6 +323,31 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     }
 }
 
+// The fill is inlined into this function, so it gets vectorized with AVX2 stores.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn fill_avx2<T: NativeType>(values: &mut [T], value: T) {
+    values.fill(value)
+}
+
+impl<T: NativeType> MutablePrimitiveArray<T> {
+    /// Sets every slot of this array to `value`, marking all of them as valid.
+    ///
+    /// On x86-64 CPUs supporting AVX2 the values are written with 256-bit stores.
+    pub fn simd_fill(&mut self, value: T) {
+        self.validity = None;
+
+        #[cfg(target_arch = "x86_64")]
+        if std::arch::is_x86_feature_detected!("avx2") {
+            // SAFETY: we checked that the CPU supports AVX2.
+            unsafe { fill_avx2(&mut self.values, value) };
+            return;
+        }
+
+        self.values.fill(value)
+    }
+}
+
 /// Setters
 impl<T: NativeType> MutablePrimitiveArray<T> {
     /// Sets position `index` to `value`.
@@ -0,0 +1,100 @@
+use super::PrimitiveArray;
+use crate::bitmap::Bitmap;
+use crate::compute::utils::combine_validities_and;
+use crate::types::NativeType;
+
+const BLOCK_SIZE: usize = 64;
+
+impl<T: NativeType> PrimitiveArray<T> {
+    /// Apply `f` element-wise over `lhs` and `rhs`.
+    ///
+    /// The output is null where either input is null; `f` is not called for those slots. The
+    /// values are processed in blocks of 64 elements, which are branch-free when the whole block
+    /// is valid.
+    ///
+    /// # Panics
+    /// Panics if `lhs` and `rhs` don't have the same length.
+    pub fn zip_chunks<U, F>(
+        lhs: &PrimitiveArray<T>,
+        rhs: &PrimitiveArray<T>,
+        f: F,
+    ) -> PrimitiveArray<U>
+    where
+        U: NativeType,
+        F: Fn(T, T) -> U + Copy,
+    {
+        assert_eq!(lhs.len(), rhs.len(), "arrays must have the same length");
+
+        let validity = combine_validities_and(lhs.validity(), rhs.validity());
+        let values = match &validity {
+            Some(validity) if validity.unset_bits() > 0 => {
+                zip_with_validity(lhs.values(), rhs.values(), validity, f)
+            },
+            _ => zip_no_nulls(lhs.values(), rhs.values(), f),
+        };
+        PrimitiveArray::new(U::PRIMITIVE.into(), values.into(), validity)
+    }
+}
+
+#[inline(always)]
+fn zip_block<T: NativeType, U: NativeType, F: Fn(T, T) -> U>(
+    lhs: &[T],
+    rhs: &[T],
+    f: F,
+    out: &mut Vec<U>,
+) {
+    let lhs: &[T; BLOCK_SIZE] = lhs.try_into().unwrap();
+    let rhs: &[T; BLOCK_SIZE] = rhs.try_into().unwrap();
+    let block: [U; BLOCK_SIZE] = std::array::from_fn(|i| f(lhs[i], rhs[i]));
+    out.extend_from_slice(&block);
+}
+
+fn zip_no_nulls<T: NativeType, U: NativeType, F: Fn(T, T) -> U + Copy>(
+    lhs: &[T],
+    rhs: &[T],
+    f: F,
+) -> Vec<U> {
+    let mut out = Vec::with_capacity(lhs.len());
+    let lhs_blocks = lhs.chunks_exact(BLOCK_SIZE);
+    let rhs_blocks = rhs.chunks_exact(BLOCK_SIZE);
+    let (lhs_rem, rhs_rem) = (lhs_blocks.remainder(), rhs_blocks.remainder());
+
+    for (l, r) in lhs_blocks.zip(rhs_blocks) {
+        zip_block(l, r, f, &mut out);
+    }
+    out.extend(lhs_rem.iter().zip(rhs_rem).map(|(l, r)| f(*l, *r)));
+    out
+}
+
+fn zip_with_validity<T: NativeType, U: NativeType, F: Fn(T, T) -> U + Copy>(
+    lhs: &[T],
+    rhs: &[T],
+    validity: &Bitmap,
+    f: F,
+) -> Vec<U> {
+    let zip_masked = |l: &[T], r: &[T], mask: u64, out: &mut Vec<U>| {
+        out.extend(l.iter().zip(r).enumerate().map(|(i, (l, r))| {
+            if (mask >> i) & 1 == 1 {
+                f(*l, *r)
+            } else {
+                U::default()
+            }
+        }));
+    };
+
+    let mut out = Vec::with_capacity(lhs.len());
+    let lhs_blocks = lhs.chunks_exact(BLOCK_SIZE);
+    let rhs_blocks = rhs.chunks_exact(BLOCK_SIZE);
+    let (lhs_rem, rhs_rem) = (lhs_blocks.remainder(), rhs_blocks.remainder());
+    let mut masks = validity.chunks::<u64>();
+
+    for ((l, r), mask) in lhs_blocks.zip(rhs_blocks).zip(masks.by_ref()) {
+        match mask {
+            u64::MAX => zip_block(l, r, f, &mut out),
+            0 => out.extend(std::iter::repeat_n(U::default(), BLOCK_SIZE)),
+            mask => zip_masked(l, r, mask, &mut out),
+        }
+    }
+    zip_masked(lhs_rem, rhs_rem, masks.remainder(), &mut out);
+    out
+}
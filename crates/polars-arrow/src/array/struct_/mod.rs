@@ -240,6 +240,17 @@ impl StructArray {
         debug_assert_eq!(self.values().len(), fields.len());
         fields
     }
+
+    /// Returns the names of the fields of this [`StructArray`].
+    pub fn field_names(&self) -> Vec<&str> {
+        self.fields().iter().map(|f| f.name.as_str()).collect()
+    }
+
+    /// Returns the values of the first field called `name`, if any.
+    pub fn field_by_name(&self, name: &str) -> Option<&Box<dyn Array>> {
+        let idx = self.fields().iter().position(|f| f.name == name)?;
+        Some(&self.values[idx])
+    }
 }
 
 impl StructArray {
@@ -266,6 +277,15 @@ impl Array for StructArray {
         self.validity.as_ref()
     }
 
+    fn physical_null_count(&self) -> usize {
+        self.null_count()
+            + self
+                .values
+                .iter()
+                .map(|v| v.physical_null_count())
+                .sum::<usize>()
+    }
+
     #[inline]
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
         Box::new(self.clone().with_validity(validity))
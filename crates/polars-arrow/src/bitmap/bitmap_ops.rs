@@ -410,5 +410,19 @@ mod tests {
 
             prop_assert_eq!(kernel_out, reference_out);
         }
+
+        #[test]
+        fn test_and_not(
+            (lhs, rhs) in two_equal_length_bitmaps()
+        ) {
+            let reference_out = lhs.iter().zip(rhs.iter()).map(|(l, r)| l & !r).collect::<Vec<_>>();
+
+            let kernel_out = lhs.and_not(&rhs);
+            prop_assert_eq!(kernel_out.iter().collect::<Vec<_>>(), reference_out.clone());
+
+            let mut mutable = lhs.clone().make_mut();
+            mutable.and_not_assign(&rhs);
+            prop_assert_eq!(mutable.iter().collect::<Vec<_>>(), reference_out);
+        }
     }
 }
@@ -7,7 +7,7 @@ use polars_error::{PolarsResult, polars_bail};
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use super::utils::{self, BitChunk, BitChunks, BitmapIter, count_zeros, fmt, get_bit_unchecked};
-use super::{IntoIter, MutableBitmap, chunk_iter_to_vec, num_intersections_with};
+use super::{IntoIter, MutableBitmap, and_not, chunk_iter_to_vec, num_intersections_with};
 use crate::array::Splitable;
 use crate::bitmap::aligned::AlignedBitmapSlice;
 use crate::bitmap::iterator::{
@@ -432,6 +432,19 @@ impl Bitmap {
         count_zeros(&self.storage, self.offset + offset, length)
     }
 
+    /// Counts the unset bits in the range `start..end`.
+    ///
+    /// The prefix and suffix words are masked and the words in between are counted whole, in a
+    /// single pass.
+    ///
+    /// # Panics
+    /// Panics iff `start > end` or `end > self.len()`.
+    #[inline]
+    pub fn count_zeros_range(&self, start: usize, end: usize) -> usize {
+        assert!(start <= end && end <= self.length);
+        self.null_count_range(start, end - start)
+    }
+
     /// Creates a new [`Bitmap`] from a slice and length.
     /// # Panic
     /// Panics iff `length > bytes.len() * 8`
@@ -497,6 +510,14 @@ impl Bitmap {
         num_intersections_with(self, other)
     }
 
+    /// Computes `self & !other`.
+    ///
+    /// # Panics
+    /// Panics iff `self.len() != other.len()`.
+    pub fn and_not(&self, other: &Self) -> Self {
+        and_not(self, other)
+    }
+
     /// Select between `truthy` and `falsy` based on `self`.
     ///
     /// This essentially performs:
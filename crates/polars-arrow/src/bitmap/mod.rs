@@ -23,5 +23,8 @@ pub mod bitmask;
 mod builder;
 pub use builder::*;
 
+mod validity_shape;
+pub use validity_shape::ValidityShape;
+
 #[cfg(feature = "proptest")]
 pub mod proptest;
@@ -6,7 +6,7 @@ use polars_utils::vec::PushUnchecked;
 
 use super::bitmask::BitMask;
 use super::utils::{BitChunk, BitChunks, BitChunksExactMut, BitmapIter, count_zeros, fmt};
-use super::{Bitmap, intersects_with_mut};
+use super::{Bitmap, binary_assign, intersects_with_mut};
 use crate::bitmap::utils::{get_bit_unchecked, merge_reversed, set_bit_in_byte};
 use crate::storage::SharedStorage;
 use crate::trusted_len::TrustedLen;
@@ -379,6 +379,61 @@ impl MutableBitmap {
         *byte = set_bit_in_byte(*byte, index % 8, value);
     }
 
+    /// Sets all bits in `start..end` to `value`.
+    ///
+    /// Whole 64-bit words in the range are set with
+    /// [`set_range_word_aligned`](Self::set_range_word_aligned).
+    /// # Panics
+    /// Panics iff `start > end` or `end > self.len()`.
+    pub fn set_range(&mut self, start: usize, end: usize, value: bool) {
+        assert!(start <= end && end <= self.len());
+
+        let aligned_start = start.next_multiple_of(64);
+        let aligned_end = end / 64 * 64;
+        if aligned_start >= aligned_end {
+            for i in start..end {
+                // SAFETY: `i < end <= self.len()`.
+                unsafe { self.set_unchecked(i, value) };
+            }
+            return;
+        }
+
+        // SAFETY: all bits are in bounds and the middle range is word-aligned.
+        unsafe {
+            for i in start..aligned_start {
+                self.set_unchecked(i, value);
+            }
+            self.set_range_word_aligned(aligned_start, aligned_end, value);
+            for i in aligned_end..end {
+                self.set_unchecked(i, value);
+            }
+        }
+    }
+
+    /// Sets all bits in `word_start..word_end` to `value` by writing whole bytes.
+    ///
+    /// # Panics
+    /// Panics iff `word_start` or `word_end` isn't a multiple of 64.
+    /// # Safety
+    /// Caller must ensure that `word_start <= word_end <= self.len()`.
+    #[inline]
+    pub unsafe fn set_range_word_aligned(
+        &mut self,
+        word_start: usize,
+        word_end: usize,
+        value: bool,
+    ) {
+        assert!(
+            word_start % 64 == 0 && word_end % 64 == 0,
+            "range must be aligned to 64 bits"
+        );
+        debug_assert!(word_start <= word_end && word_end <= self.len());
+        let byte = if value { 0xFF } else { 0x00 };
+        self.buffer
+            .get_unchecked_mut(word_start / 8..word_end / 8)
+            .fill(byte);
+    }
+
     /// Shrinks the capacity of the [`MutableBitmap`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.buffer.shrink_to_fit();
@@ -400,6 +455,14 @@ impl MutableBitmap {
         intersects_with_mut(self, other)
     }
 
+    /// Computes `self & !other` in-place.
+    ///
+    /// # Panics
+    /// Panics iff `self.len() != other.len()`.
+    pub fn and_not_assign(&mut self, other: &Bitmap) {
+        binary_assign::<u64, _>(self, other, |x, y| x & !y)
+    }
+
     pub fn freeze(self) -> Bitmap {
         self.into()
     }
@@ -0,0 +1,117 @@
+use super::{Bitmap, MutableBitmap};
+
+/// The shape of a validity bitmap.
+///
+/// Most validities are either absent, all set, or a single run of nulls at the start (e.g. the
+/// result of shifting or of [`new_null_array`](crate::array::new_null_array)). For those shapes
+/// null-range operations such as slicing and null counting are O(1), and the bitmap doesn't need
+/// to be materialized.
+///
+/// [`PrimitiveArray`](crate::array::PrimitiveArray) stores the shape of its validity next to the
+/// bitmap. The shape is only as precise as is known without scanning the bitmap, so detection is
+/// limited: a [`ValidityShape::NullPrefix`] only comes from
+/// [`PrimitiveArray::new_null`](crate::array::PrimitiveArray::new_null),
+/// [`PrimitiveArray::with_validity_shape`](crate::array::PrimitiveArray::with_validity_shape) or
+/// a bitmap with a cached null count equal to its length. Any other validity with nulls is
+/// [`ValidityShape::ArbitraryNulls`], even if its nulls happen to be contiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidityShape {
+    /// No value is null.
+    AllValid,
+    /// The first `n` values are null, all other values are valid.
+    NullPrefix { n: usize },
+    /// The nulls are not known to form a single run at the start; the bitmap has to be checked.
+    ArbitraryNulls,
+}
+
+impl ValidityShape {
+    /// Classify a validity bitmap.
+    pub fn from_validity(validity: Option<&Bitmap>) -> Self {
+        let Some(validity) = validity else {
+            return Self::AllValid;
+        };
+        let null_count = validity.unset_bits();
+        if null_count == 0 {
+            Self::AllValid
+        } else if validity.leading_zeros() == null_count {
+            Self::NullPrefix { n: null_count }
+        } else {
+            Self::ArbitraryNulls
+        }
+    }
+
+    /// Get the shape of a validity bitmap without scanning it.
+    ///
+    /// This is O(1): only a cached null count of zero or of the full length is used to detect
+    /// [`ValidityShape::AllValid`] and [`ValidityShape::NullPrefix`].
+    pub fn from_validity_lazy(validity: Option<&Bitmap>) -> Self {
+        let Some(validity) = validity else {
+            return Self::AllValid;
+        };
+        match validity.lazy_unset_bits() {
+            Some(0) => Self::AllValid,
+            Some(n) if n == validity.len() => Self::NullPrefix { n },
+            _ => Self::ArbitraryNulls,
+        }
+    }
+
+    /// The number of nulls in the first `len` values.
+    ///
+    /// Returns `None` for [`ValidityShape::ArbitraryNulls`], which needs the bitmap.
+    pub fn null_count(&self, len: usize) -> Option<usize> {
+        match self {
+            Self::AllValid => Some(0),
+            Self::NullPrefix { n } => Some((*n).min(len)),
+            Self::ArbitraryNulls => None,
+        }
+    }
+
+    /// Whether the value at `i` is valid.
+    ///
+    /// Returns `None` for [`ValidityShape::ArbitraryNulls`], which needs the bitmap.
+    pub fn is_valid(&self, i: usize) -> Option<bool> {
+        match self {
+            Self::AllValid => Some(true),
+            Self::NullPrefix { n } => Some(i >= *n),
+            Self::ArbitraryNulls => None,
+        }
+    }
+
+    /// Slice the shape to `length` values starting at `offset`.
+    ///
+    /// This is O(1). A slice of [`ValidityShape::ArbitraryNulls`] stays
+    /// [`ValidityShape::ArbitraryNulls`], as the shape of the sliced bitmap is unknown.
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
+        match self {
+            Self::AllValid => Self::AllValid,
+            Self::NullPrefix { n } => match n.saturating_sub(offset).min(length) {
+                0 => Self::AllValid,
+                n => Self::NullPrefix { n },
+            },
+            Self::ArbitraryNulls => Self::ArbitraryNulls,
+        }
+    }
+
+    /// Materialize the validity of an array of length `len`.
+    ///
+    /// Returns `None` if all values are valid.
+    ///
+    /// # Panics
+    /// Panics if the shape is [`ValidityShape::ArbitraryNulls`], which has no bitmap to
+    /// materialize.
+    pub fn into_validity(self, len: usize) -> Option<Bitmap> {
+        match self {
+            Self::AllValid => None,
+            Self::NullPrefix { n } => {
+                let n = n.min(len);
+                let mut bitmap = MutableBitmap::with_capacity(len);
+                bitmap.extend_constant(n, false);
+                bitmap.extend_constant(len - n, true);
+                Some(bitmap.freeze())
+            },
+            Self::ArbitraryNulls => {
+                panic!("cannot materialize the validity of an arbitrary null shape")
+            },
+        }
+    }
+}
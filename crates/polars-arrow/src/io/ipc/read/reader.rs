@@ -1,4 +1,5 @@
 use std::io::{Read, Seek};
+use std::sync::Arc;
 
 use polars_error::PolarsResult;
 
@@ -6,8 +7,8 @@ use super::common::*;
 use super::file::{get_message_from_block, get_record_batch};
 use super::{Dictionaries, FileMetadata, read_batch, read_file_dictionaries};
 use crate::array::Array;
-use crate::datatypes::ArrowSchema;
-use crate::record_batch::RecordBatchT;
+use crate::datatypes::{ArrowSchema, ArrowSchemaRef};
+use crate::record_batch::{RecordBatchReader, RecordBatchT};
 
 /// An iterator of [`RecordBatchT`]s from an Arrow IPC file.
 pub struct FileReader<R: Read + Seek> {
@@ -201,3 +202,12 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         Some(chunk)
     }
 }
+
+impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
+    fn schema(&self) -> ArrowSchemaRef {
+        match &self.projection {
+            Some(projection) => Arc::new(projection.schema.clone()),
+            None => self.metadata.schema.clone(),
+        }
+    }
+}
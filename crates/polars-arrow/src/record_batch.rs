@@ -111,3 +111,52 @@ impl<A: AsRef<dyn Array>> std::ops::Deref for RecordBatchT<A> {
         self.arrays()
     }
 }
+
+/// An iterator over [`RecordBatch`]es that share a schema.
+pub trait RecordBatchReader: Iterator<Item = PolarsResult<RecordBatch>> {
+    /// The schema of the record batches.
+    fn schema(&self) -> ArrowSchemaRef;
+}
+
+/// A [`RecordBatchReader`] over record batches in memory.
+#[derive(Debug, Clone)]
+pub struct RecordBatchVecReader {
+    schema: ArrowSchemaRef,
+    batches: std::vec::IntoIter<RecordBatch>,
+}
+
+impl RecordBatchVecReader {
+    /// Creates a new [`RecordBatchVecReader`].
+    ///
+    /// # Error
+    ///
+    /// I.f.f. the schema of any of the batches does not match `schema`
+    pub fn try_new(schema: ArrowSchemaRef, batches: Vec<RecordBatch>) -> PolarsResult<Self> {
+        polars_ensure!(
+            batches.iter().all(|batch| batch.schema() == schema.as_ref()),
+            SchemaMismatch: "RecordBatchVecReader requires all batches to have the same schema",
+        );
+        Ok(Self {
+            schema,
+            batches: batches.into_iter(),
+        })
+    }
+}
+
+impl Iterator for RecordBatchVecReader {
+    type Item = PolarsResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches.next().map(Ok)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.batches.size_hint()
+    }
+}
+
+impl RecordBatchReader for RecordBatchVecReader {
+    fn schema(&self) -> ArrowSchemaRef {
+        self.schema.clone()
+    }
+}
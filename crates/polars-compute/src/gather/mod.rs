@@ -24,6 +24,9 @@ use arrow::array::{
 };
 use arrow::datatypes::{ArrowDataType, IdxArr};
 use arrow::types::Index;
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::IdxSize;
+use polars_utils::index::check_bounds;
 
 pub mod binary;
 pub mod binview;
@@ -86,6 +89,23 @@ pub unsafe fn take_unchecked(values: &dyn Array, indices: &IdxArr) -> Box<dyn Ar
     }
 }
 
+/// Checked version of [`take_unchecked`] that can be used on any `&dyn Array`.
+///
+/// Returns an error if any of the non-null `indices` is out of bounds of `values`.
+pub fn take_dyn(values: &dyn Array, indices: &IdxArr) -> PolarsResult<Box<dyn Array>> {
+    let len = values.len() as IdxSize;
+    if indices.has_nulls() {
+        polars_ensure!(
+            indices.non_null_values_iter().all(|i| i < len),
+            OutOfBounds: "gather indices are out of bounds"
+        );
+    } else {
+        check_bounds(indices.values(), len)?;
+    }
+    // SAFETY: we just checked the bounds.
+    Ok(unsafe { take_unchecked(values, indices) })
+}
+
 /// Naive default implementation
 unsafe fn take_unchecked_impl_generic<T>(
     values: &T,
@@ -149,3 +169,57 @@ where
             .collect_arr_trusted_with_dtype(values.dtype().clone()),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{Int32Array, ListArray};
+    use arrow::bitmap::Bitmap;
+    use arrow::datatypes::Field;
+    use arrow::offset::OffsetsBuffer;
+
+    use super::*;
+
+    #[test]
+    fn test_take_dyn() {
+        let indices = IdxArr::from([Some(2), None, Some(0)]);
+
+        let values = Int32Array::from_slice([1, 2, 3]);
+        let expected = Int32Array::from([Some(3), None, Some(1)]);
+        assert_eq!(take_dyn(&values, &indices).unwrap(), expected.boxed());
+
+        let long = b"a value that is too long to be inlined".as_slice();
+        let values = BinaryViewArray::from_slice_values([long, b"b", b"c"]);
+        let expected = BinaryViewArray::from_slice([Some(b"c".as_slice()), None, Some(long)]);
+        assert_eq!(take_dyn(&values, &indices).unwrap(), expected.boxed());
+
+        // [[1, 2], [], [3]]
+        let dtype = ArrowDataType::LargeList(Box::new(Field::new(
+            "item".into(),
+            ArrowDataType::Int32,
+            true,
+        )));
+        let values = ListArray::<i64>::new(
+            dtype.clone(),
+            OffsetsBuffer::try_from(vec![0, 2, 2, 3]).unwrap(),
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+            None,
+        );
+        let expected = ListArray::<i64>::new(
+            dtype,
+            OffsetsBuffer::try_from(vec![0, 1, 1, 3]).unwrap(),
+            Int32Array::from_slice([3, 1, 2]).boxed(),
+            Some(Bitmap::from([true, false, true])),
+        );
+        assert_eq!(take_dyn(&values, &indices).unwrap(), expected.boxed());
+
+        // The value behind a null index isn't checked.
+        let indices =
+            IdxArr::from_vec(vec![1, 100]).with_validity(Some(Bitmap::from([true, false])));
+        let expected = Int32Array::from([Some(2), None]);
+        let values = Int32Array::from_slice([1, 2, 3]);
+        assert_eq!(take_dyn(&values, &indices).unwrap(), expected.boxed());
+
+        let indices = IdxArr::from_slice([0, 3]);
+        assert!(take_dyn(&values, &indices).is_err());
+    }
+}
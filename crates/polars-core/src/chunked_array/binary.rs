@@ -5,6 +5,7 @@ use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
 
 use crate::POOL;
+use crate::chunked_array::builder::get_list_builder;
 use crate::prelude::*;
 use crate::utils::{_set_partition_size, _split_offsets};
 
@@ -76,3 +77,106 @@ where
         }
     }
 }
+
+impl BinaryChunked {
+    /// Compute the polynomial rolling hash of every window of `window_size` bytes in each value.
+    ///
+    /// The window starting at byte `i` hashes to
+    /// `sum(byte[i + j] * base^j for j in 0..window_size) mod modulus`, as used by e.g. Rabin-Karp.
+    /// Each window is derived from its successor in O(1). Values shorter than `window_size` give
+    /// an empty list, null values give null.
+    pub fn rolling_hash_fingerprint(
+        &self,
+        window_size: usize,
+        base: u64,
+        modulus: u64,
+    ) -> PolarsResult<ListChunked> {
+        polars_ensure!(
+            window_size > 0,
+            InvalidOperation: "`window_size` must be greater than zero"
+        );
+        polars_ensure!(modulus > 0, InvalidOperation: "`modulus` must be greater than zero");
+
+        let modulus = modulus as u128;
+        let mul = |a: u64, b: u64| ((a as u128 * b as u128) % modulus) as u64;
+        let base = (base as u128 % modulus) as u64;
+        // base^(window_size - 1), the weight of the last byte in a window.
+        let top_weight = (1..window_size).fold((1 % modulus) as u64, |acc, _| mul(acc, base));
+
+        let mut builder = get_list_builder(
+            &DataType::UInt64,
+            self.len(),
+            self.len(),
+            self.name().clone(),
+        );
+        let mut hashes = Vec::new();
+        for opt_bytes in self.iter() {
+            let Some(bytes) = opt_bytes else {
+                builder.append_null();
+                continue;
+            };
+            hashes.clear();
+            if bytes.len() >= window_size {
+                let n_windows = bytes.len() - window_size + 1;
+                hashes.resize(n_windows, 0);
+                // Hash the last window directly, then walk backwards with
+                // H(i) = byte[i] + base * (H(i + 1) - byte[i + w] * base^(w - 1)).
+                let last = n_windows - 1;
+                hashes[last] = bytes[last..].iter().rev().fold(0, |acc, &b| {
+                    ((mul(acc, base) as u128 + b as u128) % modulus) as u64
+                });
+                for i in (0..last).rev() {
+                    let dropped = mul(bytes[i + window_size] as u64, top_weight);
+                    let rest = (hashes[i + 1] as u128 + modulus - dropped as u128) % modulus;
+                    hashes[i] =
+                        ((mul(rest as u64, base) as u128 + bytes[i] as u128) % modulus) as u64;
+                }
+            }
+            builder.append_series(
+                &UInt64Chunked::from_slice(PlSmallStr::EMPTY, &hashes).into_series(),
+            )?;
+        }
+        Ok(builder.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rolling_hash_fingerprint() -> PolarsResult<()> {
+        let ca = BinaryChunked::from_iter([
+            Some(&b"abcab"[..]),
+            None,
+            Some(&b"a"[..]),
+            Some(&b"ab"[..]),
+        ]);
+        let (base, modulus) = (257, 1_000_000_007);
+        let out = ca.rolling_hash_fingerprint(2, base, modulus)?;
+
+        let naive = |w: &[u8]| {
+            w.iter()
+                .rev()
+                .fold(0u64, |acc, &b| (acc * base + b as u64) % modulus)
+        };
+        let expected_0 = b"abcab".windows(2).map(naive).collect::<Vec<_>>();
+        assert_eq!(expected_0[0], expected_0[3]);
+
+        let row = out.get_as_series(0).unwrap();
+        assert_eq!(
+            Vec::from(row.u64()?),
+            expected_0.into_iter().map(Some).collect::<Vec<_>>()
+        );
+        assert!(out.get_as_series(1).is_none());
+        assert!(out.get_as_series(2).unwrap().is_empty());
+        assert_eq!(
+            Vec::from(out.get_as_series(3).unwrap().u64()?),
+            &[Some(naive(&b"ab"[..]))]
+        );
+
+        assert!(ca.rolling_hash_fingerprint(0, base, modulus).is_err());
+        assert!(ca.rolling_hash_fingerprint(2, base, 0).is_err());
+        Ok(())
+    }
+}
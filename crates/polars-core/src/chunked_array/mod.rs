@@ -930,6 +930,14 @@ pub(crate) mod test {
         assert_eq!(new.len(), 2)
     }
 
+    #[test]
+    fn apply_optional() {
+        let a = Int32Chunked::new(PlSmallStr::from_static("a"), &[Some(1), None, Some(4)]);
+        let out: Float64Chunked = a.apply_optional(|v| (v % 2 == 0).then(|| v as f64 / 2.0));
+        assert_eq!(Vec::from(&out), &[None, None, Some(2.0)]);
+        assert_eq!(out.name().as_str(), "a");
+    }
+
     #[test]
     fn cast() {
         let a = get_chunked_array();
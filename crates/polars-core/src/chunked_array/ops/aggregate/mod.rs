@@ -679,6 +679,58 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_quantile_exact() {
+        let ca = Int32Chunked::new(
+            PlSmallStr::EMPTY,
+            &[Some(7), None, Some(1), Some(4), Some(2), None],
+        );
+        assert_eq!(
+            ca.quantile_exact(0.5, QuantileMethod::Nearest).unwrap(),
+            Some(4)
+        );
+        assert_eq!(
+            ca.quantile_exact(0.5, QuantileMethod::Lower).unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            ca.quantile_exact(0.5, QuantileMethod::Higher).unwrap(),
+            Some(4)
+        );
+        assert_eq!(
+            ca.quantile_exact(0.5, QuantileMethod::Midpoint).unwrap(),
+            Some(3)
+        );
+        assert_eq!(
+            ca.quantile_exact(1.0, QuantileMethod::Linear).unwrap(),
+            Some(7)
+        );
+        assert!(ca.quantile_exact(1.5, QuantileMethod::Linear).is_err());
+
+        let ca = Float64Chunked::from_slice(PlSmallStr::EMPTY, &[4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(
+            ca.quantile_exact(0.5, QuantileMethod::Linear).unwrap(),
+            Some(2.5)
+        );
+
+        let ca = Float64Chunked::full_null(PlSmallStr::EMPTY, 3);
+        assert_eq!(
+            ca.quantile_exact(0.5, QuantileMethod::Linear).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nth_element() {
+        let ca = Int32Chunked::new(PlSmallStr::EMPTY, &[Some(5), None, Some(1), Some(3)]);
+        assert_eq!(ca.nth_element(0, true).unwrap(), Some(1));
+        assert_eq!(ca.nth_element(2, true).unwrap(), Some(5));
+        assert_eq!(ca.nth_element(3, true).unwrap(), None);
+        assert_eq!(ca.nth_element(0, false).unwrap(), None);
+        assert_eq!(ca.nth_element(1, false).unwrap(), Some(1));
+        assert!(ca.nth_element(4, false).is_err());
+    }
+
     #[test]
     fn test_quantile_all_null() {
         let test_f32 = Float32Chunked::from_slice_options(PlSmallStr::EMPTY, &[None, None, None]);
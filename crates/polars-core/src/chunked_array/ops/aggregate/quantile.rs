@@ -1,3 +1,4 @@
+use num_traits::NumCast;
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
@@ -147,6 +148,95 @@ where
     Ok(opt)
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Get the exact quantile of the [`ChunkedArray`] in its native type.
+    ///
+    /// The non-null values are copied into a buffer in which the quantile is found with a
+    /// selection algorithm, which is `O(n)` on average. The result is deterministic and doesn't
+    /// depend on the chunking or the order of the values, but it costs an allocation the size of
+    /// the array; [`ChunkQuantile::quantile`] avoids the copy for sorted data.
+    ///
+    /// The interpolating methods compute in `f64` and cast the result back to the native type,
+    /// which truncates for integers.
+    pub fn quantile_exact(
+        &self,
+        quantile: f64,
+        method: QuantileMethod,
+    ) -> PolarsResult<Option<T::Native>> {
+        polars_ensure!(
+            (0.0..=1.0).contains(&quantile),
+            ComputeError: "`quantile` should be between 0.0 and 1.0",
+        );
+
+        let mut vals: Vec<T::Native> = if self.has_nulls() {
+            self.iter().flatten().collect()
+        } else {
+            self.into_no_null_iter().collect()
+        };
+        if vals.is_empty() {
+            return Ok(None);
+        }
+
+        let (idx, float_idx, top_idx) = quantile_idx(quantile, vals.len(), 0, method);
+        let (_lhs, lower, rhs) = vals.select_nth_unstable_by(idx, TotalOrd::tot_cmp);
+        let lower = *lower;
+        if idx == top_idx {
+            return Ok(Some(lower));
+        }
+
+        let upper = || rhs.iter().copied().min_by(TotalOrd::tot_cmp).unwrap();
+        let out = match method {
+            QuantileMethod::Midpoint => NumCast::from(midpoint_interpol(
+                lower.to_f64().unwrap(),
+                upper().to_f64().unwrap(),
+            )),
+            QuantileMethod::Linear => NumCast::from(linear_interpol(
+                lower.to_f64().unwrap(),
+                upper().to_f64().unwrap(),
+                idx,
+                float_idx,
+            )),
+            _ => Some(lower),
+        };
+        Ok(out)
+    }
+
+    /// Get the `k`-th smallest element (zero-based) without sorting the whole array.
+    ///
+    /// This uses a selection algorithm, which is `O(n)` on average. Nulls are ordered like
+    /// [`arg_sort`](ChunkedArray::arg_sort) with `nulls_last`; `None` is returned if the `k`-th
+    /// element is null.
+    pub fn nth_element(&self, k: usize, nulls_last: bool) -> PolarsResult<Option<T::Native>> {
+        polars_ensure!(
+            k < self.len(),
+            OutOfBounds: "index {} is out of bounds for array of length {}", k, self.len()
+        );
+
+        let null_count = self.null_count();
+        let k = if nulls_last {
+            k
+        } else if k < null_count {
+            return Ok(None);
+        } else {
+            k - null_count
+        };
+        if k >= self.len() - null_count {
+            return Ok(None);
+        }
+
+        let mut vals: Vec<T::Native> = if null_count > 0 {
+            self.iter().flatten().collect()
+        } else {
+            self.into_no_null_iter().collect()
+        };
+        let (_, nth, _) = vals.select_nth_unstable_by(k, TotalOrd::tot_cmp);
+        Ok(Some(*nth))
+    }
+}
+
 impl<T> ChunkQuantile<f64> for ChunkedArray<T>
 where
     T: PolarsIntegerType,
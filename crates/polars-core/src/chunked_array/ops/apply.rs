@@ -158,6 +158,26 @@ where
 }
 
 impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Apply `f` to the non-null values, where `f` returning `None` results in a null.
+    ///
+    /// This filters and transforms the values in a single pass.
+    pub fn apply_optional<B, F>(&self, f: F) -> ChunkedArray<B>
+    where
+        B: PolarsNumericType,
+        F: Fn(T::Native) -> Option<B::Native>,
+    {
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| -> PrimitiveArray<B::Native> {
+                if arr.null_count() == 0 {
+                    arr.values_iter().map(|v| f(*v)).collect_arr()
+                } else {
+                    arr.iter().map(|opt| opt.and_then(|v| f(*v))).collect_arr()
+                }
+            });
+        ChunkedArray::from_chunk_iter(self.name().clone(), chunks)
+    }
+
     /// Cast a numeric array to another numeric data type and apply a function in place.
     /// This saves an allocation.
     pub fn cast_and_apply_in_place<F, S>(&self, f: F) -> ChunkedArray<S>
@@ -1,4 +1,7 @@
-use super::BooleanChunked;
+use arrow::bitmap::{Bitmap, MutableBitmap};
+
+use crate::prelude::*;
+use crate::series::IsSorted;
 
 fn first_true_idx_impl(ca: &BooleanChunked, invert: bool) -> Option<usize> {
     let null_count = ca.null_count();
@@ -48,6 +51,31 @@ fn first_true_idx_impl(ca: &BooleanChunked, invert: bool) -> Option<usize> {
     None
 }
 
+fn true_indices_impl(ca: &BooleanChunked, invert: bool) -> IdxCa {
+    let len = if invert {
+        ca.num_falses()
+    } else {
+        ca.num_trues()
+    };
+    let mut out = Vec::with_capacity(len);
+    let mut offset = 0;
+    for arr in ca.downcast_iter() {
+        let values = arr.values();
+        let mask: Bitmap = match (invert, arr.validity()) {
+            (false, None) => values.clone(),
+            (false, Some(validity)) => values & validity,
+            (true, None) => !values,
+            (true, Some(validity)) => arrow::bitmap::and_not(validity, values),
+        };
+        out.extend(mask.true_idx_iter().map(|i| (offset + i) as IdxSize));
+        offset += arr.len();
+    }
+
+    let mut out = IdxCa::from_vec(ca.name().clone(), out);
+    out.set_sorted_flag(IsSorted::Ascending);
+    out
+}
+
 impl BooleanChunked {
     pub fn num_trues(&self) -> usize {
         self.downcast_iter()
@@ -74,4 +102,102 @@ impl BooleanChunked {
     pub fn first_false_idx(&self) -> Option<usize> {
         first_true_idx_impl(self, true)
     }
+
+    /// Get the indices of the `true` values. Null values are skipped.
+    ///
+    /// This collects the set bits directly in O(n) and doesn't need a sort.
+    pub fn true_indices(&self) -> IdxCa {
+        true_indices_impl(self, false)
+    }
+
+    /// Get the indices of the `false` values. Null values are skipped.
+    pub fn false_indices(&self) -> IdxCa {
+        true_indices_impl(self, true)
+    }
+
+    /// Pack the values into a single-element [`BinaryChunked`] of bytes, least significant bit
+    /// first. Null values are packed as `false` and the last byte is padded with zeros.
+    pub fn pack_bits(&self) -> PolarsResult<BinaryChunked> {
+        let ca = self.rechunk();
+        let arr = ca.downcast_as_array();
+        let bitmap = match arr.validity() {
+            None => arr.values().clone(),
+            Some(validity) => arr.values() & validity,
+        };
+
+        let (bytes, offset, len) = bitmap.as_slice();
+        let mut packed = if offset == 0 {
+            bytes[..len.div_ceil(8)].to_vec()
+        } else {
+            MutableBitmap::from_iter(bitmap.iter()).as_slice().to_vec()
+        };
+        if len % 8 != 0 {
+            *packed.last_mut().unwrap() &= (1u8 << (len % 8)) - 1;
+        }
+
+        Ok(BinaryChunked::from_slice(
+            self.name().clone(),
+            &[packed.as_slice()],
+        ))
+    }
+}
+
+impl BinaryChunked {
+    /// Unpack the first `len` bits of a single-element [`BinaryChunked`] created by
+    /// [`BooleanChunked::pack_bits`].
+    pub fn unpack_bits(&self, len: usize) -> PolarsResult<BooleanChunked> {
+        polars_ensure!(
+            self.len() == 1,
+            ShapeMismatch: "'unpack_bits' expected a single element, got {}", self.len()
+        );
+        let Some(bytes) = self.get(0) else {
+            polars_bail!(ComputeError: "'unpack_bits' cannot unpack a null value");
+        };
+        polars_ensure!(
+            len <= bytes.len() * 8,
+            OutOfBounds: "cannot unpack {} bits from {} bytes", len, bytes.len()
+        );
+
+        let bitmap = Bitmap::try_new(bytes[..len.div_ceil(8)].to_vec(), len)?;
+        Ok(BooleanChunked::from_bitmap(self.name().clone(), bitmap))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_true_false_indices() {
+        let mut ca = BooleanChunked::new("a".into(), &[Some(true), None, Some(false)]);
+        ca.append(&BooleanChunked::new("a".into(), &[Some(false), Some(true)]))
+            .unwrap();
+
+        assert_eq!(Vec::from(&ca.true_indices()), &[Some(0), Some(4)]);
+        assert_eq!(Vec::from(&ca.false_indices()), &[Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn test_pack_bits() -> PolarsResult<()> {
+        let values = [
+            true, false, true, true, false, false, false, false, true, true,
+        ];
+        let mut ca = BooleanChunked::new("a".into(), &values[..4]);
+        ca.append(&BooleanChunked::new("a".into(), &values[4..]))?;
+
+        let packed = ca.pack_bits()?;
+        assert_eq!(packed.get(0), Some([0b0000_1101u8, 0b0000_0011].as_slice()));
+
+        let unpacked = packed.unpack_bits(values.len())?;
+        assert_eq!(
+            Vec::from(&unpacked),
+            values.iter().copied().map(Some).collect::<Vec<_>>()
+        );
+
+        let ca = BooleanChunked::new("a".into(), &[Some(true), None, Some(true)]);
+        let packed = ca.slice(1, 2).pack_bits()?;
+        assert_eq!(packed.get(0), Some([0b0000_0010u8].as_slice()));
+        assert!(packed.unpack_bits(9).is_err());
+        Ok(())
+    }
 }
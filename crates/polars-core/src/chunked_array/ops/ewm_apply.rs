@@ -0,0 +1,58 @@
+use num_traits::AsPrimitive;
+
+use crate::prelude::*;
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: AsPrimitive<f64>,
+{
+    /// Compute an exponentially weighted moving average with a custom update function.
+    ///
+    /// The average starts at the first non-null value. Every next value is computed as
+    /// `f(current_value, prev_ema, alpha)`. Null values produce a null and don't update the
+    /// average.
+    pub fn ewm_apply<F>(&self, alpha: f64, f: F) -> PolarsResult<Float64Chunked>
+    where
+        F: Fn(f64, f64, f64) -> f64,
+    {
+        polars_ensure!(
+            alpha > 0.0 && alpha <= 1.0,
+            ComputeError: "`alpha` must be in the range (0, 1], got {}", alpha
+        );
+
+        let mut prev_ema: Option<f64> = None;
+        let out: Float64Chunked = self
+            .iter()
+            .map(|opt_v| {
+                let v: f64 = opt_v?.as_();
+                let ema = match prev_ema {
+                    None => v,
+                    Some(prev_ema) => f(v, prev_ema, alpha),
+                };
+                prev_ema = Some(ema);
+                Some(ema)
+            })
+            .collect_trusted();
+        Ok(out.with_name(self.name().clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ewm_apply() -> PolarsResult<()> {
+        let ca = Float32Chunked::new("a".into(), [None, Some(1.0), Some(3.0), None, Some(5.0)]);
+
+        let out = ca.ewm_apply(0.5, |v, prev, alpha| alpha * v + (1.0 - alpha) * prev)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[None, Some(1.0), Some(2.0), None, Some(3.5)]
+        );
+
+        assert!(ca.ewm_apply(0.0, |v, _, _| v).is_err());
+        Ok(())
+    }
+}
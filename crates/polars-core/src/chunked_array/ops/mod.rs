@@ -20,6 +20,7 @@ pub(crate) mod compare_inner;
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
 pub(crate) mod downcast;
+mod ewm_apply;
 pub(crate) mod explode;
 mod explode_and_offsets;
 mod extend;
@@ -39,6 +40,7 @@ pub mod search_sorted;
 mod set;
 mod shift;
 pub mod sort;
+mod sparse;
 #[cfg(feature = "algorithm_group_by")]
 pub(crate) mod unique;
 #[cfg(feature = "zip_with")]
@@ -46,7 +46,10 @@ impl Default for RollingOptionsFixedWindow {
 
 #[cfg(feature = "rolling_window")]
 mod inner_mod {
-    use num_traits::Zero;
+    use std::collections::VecDeque;
+
+    use num_traits::{One, Zero};
+    use polars_compute::arithmetic::pl_num::PlNumArithmetic;
 
     use crate::chunked_array::cast::CastOptions;
     use crate::prelude::*;
@@ -76,6 +79,127 @@ mod inner_mod {
         (start, end - start)
     }
 
+    impl Series {
+        /// Get the first non-null value in every rolling window.
+        ///
+        /// A window yields null if it has fewer than `min_periods` non-null values. This runs in
+        /// `O(n)` by keeping the positions of the valid values of the current window in a queue.
+        pub fn rolling_first_valid(
+            &self,
+            options: RollingOptionsFixedWindow,
+        ) -> PolarsResult<Series> {
+            check_input(options.window_size, options.min_periods)?;
+            polars_ensure!(
+                options.weights.is_none(),
+                InvalidOperation: "'rolling_first_valid' does not support `weights`"
+            );
+
+            let len = self.len();
+            let window_size = std::cmp::min(len, options.window_size);
+            let is_valid = self.is_not_null();
+            let mut is_valid = is_valid.into_no_null_iter();
+
+            // Positions of the non-null values in the current window.
+            let mut valid = VecDeque::with_capacity(window_size);
+            let mut end = 0;
+            let idx = (0..len).map(|i| {
+                let (start, size) = window_edges(i, len, window_size, options.center);
+                while end < start + size {
+                    if is_valid.next().unwrap() {
+                        valid.push_back(end);
+                    }
+                    end += 1;
+                }
+                while valid.front().is_some_and(|&j| j < start) {
+                    valid.pop_front();
+                }
+
+                if size < options.min_periods || valid.len() < options.min_periods {
+                    None
+                } else {
+                    valid.front().map(|&j| j as IdxSize)
+                }
+            });
+            let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx);
+
+            // SAFETY: the indices are in bounds.
+            Ok(unsafe { self.take_unchecked(&idx) })
+        }
+    }
+
+    impl<T> ChunkedArray<T>
+    where
+        T: PolarsNumericType,
+        T::Native: PlNumArithmetic,
+    {
+        /// Get the product of every rolling window.
+        ///
+        /// Null values are ignored and a window yields null if it has fewer than `min_periods`
+        /// non-null values. Integer products wrap on overflow. This runs in `O(n)` by keeping the
+        /// window in two stacks of partial products, so that values never have to be divided out
+        /// of the product, which doesn't work for zeros, NaNs or wrapped integers.
+        pub fn rolling_product(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+            check_input(options.window_size, options.min_periods)?;
+            polars_ensure!(
+                options.weights.is_none(),
+                InvalidOperation: "'rolling_product' does not support `weights`"
+            );
+
+            let len = self.len();
+            let window_size = std::cmp::min(len, options.window_size);
+            let one = T::Native::one();
+
+            // The oldest non-null values of the window, each with the product of itself and the
+            // values inserted after it. The oldest value is on top.
+            let mut front: Vec<T::Native> = Vec::with_capacity(window_size);
+            // The newer non-null values of the window and their product.
+            let mut back: Vec<T::Native> = Vec::with_capacity(window_size);
+            let mut back_product = one;
+            let mut n_valid = 0;
+
+            let mut entering = self.into_iter();
+            let mut leaving = self.into_iter();
+            let (mut start, mut end) = (0, 0);
+            let out: ChunkedArray<T> = (0..len)
+                .map(|i| {
+                    let (new_start, size) = window_edges(i, len, window_size, options.center);
+                    while end < new_start + size {
+                        if let Some(v) = entering.next().unwrap() {
+                            back.push(v);
+                            back_product = back_product.wrapping_mul(v);
+                            n_valid += 1;
+                        }
+                        end += 1;
+                    }
+                    while start < new_start {
+                        if leaving.next().unwrap().is_some() {
+                            if front.is_empty() {
+                                let mut acc = one;
+                                for v in back.drain(..).rev() {
+                                    acc = acc.wrapping_mul(v);
+                                    front.push(acc);
+                                }
+                                back_product = one;
+                            }
+                            front.pop();
+                            n_valid -= 1;
+                        }
+                        start += 1;
+                    }
+
+                    if size < options.min_periods || n_valid < options.min_periods {
+                        None
+                    } else {
+                        let front_product = front.last().copied().unwrap_or(one);
+                        Some(front_product.wrapping_mul(back_product))
+                    }
+                })
+                .collect();
+
+            Ok(out.with_name(self.name().clone()).into_series())
+        }
+    }
+
     impl<T: PolarsNumericType> ChunkRollApply for ChunkedArray<T> {
         /// Apply a rolling custom function. This is pretty slow because of dynamic dispatch.
         fn rolling_map(
@@ -201,3 +325,105 @@ mod inner_mod {
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "rolling_window")]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rolling_first_valid() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[None, Some(1), None, None, Some(4), None, None, None],
+        );
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 1,
+            ..Default::default()
+        };
+
+        let out = s.rolling_first_valid(options.clone())?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(4),
+                Some(4),
+                Some(4),
+                None
+            ]
+        );
+
+        let out = s.rolling_first_valid(RollingOptionsFixedWindow {
+            center: true,
+            ..options
+        })?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(4),
+                Some(4),
+                Some(4),
+                None,
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_product() -> PolarsResult<()> {
+        let ca = Int32Chunked::new(
+            "a".into(),
+            &[Some(1), Some(2), None, Some(0), Some(3), Some(4), Some(5)],
+        );
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 2,
+            ..Default::default()
+        };
+
+        let out = ca.rolling_product(options.clone())?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[None, Some(2), Some(2), Some(0), Some(0), Some(0), Some(60)]
+        );
+
+        let out = ca.rolling_product(RollingOptionsFixedWindow {
+            center: true,
+            ..options
+        })?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[
+                Some(2),
+                Some(2),
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(60),
+                Some(20)
+            ]
+        );
+
+        // A NaN doesn't affect the product once it left the window.
+        let ca = Float64Chunked::new("a".into(), &[f64::NAN, 2.0, 3.0, 4.0]);
+        let out = ca.rolling_product(RollingOptionsFixedWindow {
+            window_size: 2,
+            ..Default::default()
+        })?;
+        let out = out.f64()?;
+        assert!(out.get(1).unwrap().is_nan());
+        assert_eq!(out.get(2), Some(6.0));
+        assert_eq!(out.get(3), Some(12.0));
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use arrow::bitmap::MutableBitmap;
 use num_traits::{abs, clamp};
 
 use crate::prelude::*;
@@ -49,6 +50,54 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Create a [`DataFrame`] with a column `self.shift(period)` for every period in `periods`.
+    ///
+    /// The columns are named `{name}_lag_{period}` and are filled in a single pass over `self`.
+    pub fn multi_shift(&self, periods: &[i64]) -> PolarsResult<DataFrame> {
+        let len = self.len();
+        let mut values = periods
+            .iter()
+            .map(|_| vec![T::Native::default(); len])
+            .collect::<Vec<_>>();
+        let mut validity = periods
+            .iter()
+            .map(|_| MutableBitmap::from_len_zeroed(len))
+            .collect::<Vec<_>>();
+
+        let mut offset = 0;
+        for arr in self.downcast_iter() {
+            for (i, v) in arr.iter().enumerate() {
+                let Some(&v) = v else {
+                    continue;
+                };
+                let i = (offset + i) as i64;
+                for (k, &period) in periods.iter().enumerate() {
+                    let dst = i.saturating_add(period);
+                    if (0..len as i64).contains(&dst) {
+                        values[k][dst as usize] = v;
+                        validity[k].set(dst as usize, true);
+                    }
+                }
+            }
+            offset += arr.len();
+        }
+
+        let columns = periods
+            .iter()
+            .zip(values.into_iter().zip(validity))
+            .map(|(period, (values, validity))| {
+                let name = polars_utils::format_pl_smallstr!("{}_lag_{period}", self.name());
+                ChunkedArray::<T>::from_vec_validity(name, values, validity.into()).into_column()
+            })
+            .collect::<Vec<_>>();
+        DataFrame::empty_with_height(len).hstack(&columns)
+    }
+}
+
 impl ChunkShiftFill<BooleanType, Option<bool>> for BooleanChunked {
     fn shift_and_fill(&self, periods: i64, fill_value: Option<bool>) -> BooleanChunked {
         impl_shift_fill!(self, periods, fill_value)
@@ -223,6 +272,27 @@ impl ChunkShift<StructType> for StructChunked {
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_multi_shift() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a".into(), &[Some(1), None, Some(3), Some(4)]);
+
+        let df = ca.multi_shift(&[1, -2, 0, 5])?;
+        assert_eq!(
+            df.get_column_names(),
+            &["a_lag_1", "a_lag_-2", "a_lag_0", "a_lag_5"]
+        );
+        for (column, period) in df.get_columns().iter().zip([1, -2, 0, 5]) {
+            assert!(
+                column
+                    .as_materialized_series()
+                    .equals_missing(&ca.shift(period).into_series())
+            );
+        }
+        assert_eq!(ca.multi_shift(&[])?.shape(), (4, 0));
+        assert!(ca.multi_shift(&[1, 1]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_shift() {
         let ca = Int32Chunked::new(PlSmallStr::EMPTY, &[1, 2, 3]);
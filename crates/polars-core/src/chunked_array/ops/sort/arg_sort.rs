@@ -181,6 +181,51 @@ where
     ChunkedArray::with_chunk(name, IdxArr::from_data_default(Buffer::from(idx), None))
 }
 
+/// Same as [`arg_sort`], but the order of equal elements is not preserved.
+pub(super) fn arg_sort_unstable<I, J, T>(
+    name: PlSmallStr,
+    iters: I,
+    options: SortOptions,
+    null_count: usize,
+    len: usize,
+) -> IdxCa
+where
+    I: IntoIterator<Item = J>,
+    J: IntoIterator<Item = Option<T>>,
+    T: TotalOrd + Send + Sync,
+{
+    let mut vals = Vec::with_capacity(len - null_count);
+    let mut nulls_idx = Vec::with_capacity(null_count);
+    let mut count: IdxSize = 0;
+
+    for arr_iter in iters {
+        for v in arr_iter {
+            match v {
+                Some(v) => vals.push((count, v)),
+                None => nulls_idx.push(count),
+            }
+            count += 1;
+        }
+    }
+
+    sort_unstable_by_branch(vals.as_mut_slice(), options, |a, b| a.1.tot_cmp(&b.1));
+
+    let iter = vals.into_iter().map(|(idx, _v)| idx);
+    let mut idx = Vec::with_capacity(len);
+    if options.nulls_last {
+        idx.extend(iter);
+        idx.extend_from_slice(&nulls_idx);
+    } else {
+        idx.extend_from_slice(&nulls_idx);
+        idx.extend(iter);
+    }
+    if let Some(limit) = options.limit {
+        idx.truncate(limit as usize);
+    }
+
+    ChunkedArray::with_chunk(name, IdxArr::from_data_default(Buffer::from(idx), None))
+}
+
 pub(super) fn arg_sort_no_nulls<I, J, T>(
     name: PlSmallStr,
     iters: I,
@@ -20,12 +20,12 @@ use rayon::prelude::*;
 pub use slice::*;
 
 use super::row_encode::_get_rows_encoded_ca;
-use crate::POOL;
 use crate::prelude::compare_inner::TotalOrdInner;
 use crate::prelude::sort::arg_sort_multiple::*;
 use crate::prelude::*;
 use crate::series::IsSorted;
 use crate::utils::NoNull;
+use crate::{POOL, downcast_as_macro_arg_physical};
 
 fn partition_nulls<T: Copy>(
     values: &mut [T],
@@ -278,6 +278,37 @@ where
     }
 }
 
+fn arg_sort_unstable_numeric<T>(ca: &ChunkedArray<T>, mut options: SortOptions) -> IdxCa
+where
+    T: PolarsNumericType,
+{
+    options.multithreaded &= POOL.current_num_threads() > 1;
+    arg_sort_fast_path!(ca, options);
+    let iter = ca
+        .downcast_iter()
+        .map(|arr| arr.iter().map(|opt| opt.copied()));
+    arg_sort::arg_sort_unstable(ca.name().clone(), iter, options, ca.null_count(), ca.len())
+}
+
+impl Series {
+    /// Get the indices that would sort this [`Series`], without preserving the order of
+    /// equal elements.
+    ///
+    /// This is faster than [`Series::arg_sort`] for numeric and temporal data. Other data types
+    /// fall back to the stable sort.
+    pub fn argsort_unstable(&self, options: SortOptions) -> IdxCa {
+        if !(self.dtype().is_primitive_numeric() || self.dtype().is_temporal()) {
+            return self.arg_sort(options);
+        }
+
+        let s = self.to_physical_repr();
+        macro_rules! dispatch {
+            ($ca:expr) => {{ arg_sort_unstable_numeric($ca, options) }};
+        }
+        downcast_as_macro_arg_physical!(s, dispatch)
+    }
+}
+
 fn arg_sort_multiple_numeric<T: PolarsNumericType>(
     ca: &ChunkedArray<T>,
     by: &[Column],
@@ -835,6 +866,28 @@ pub(crate) fn prepare_arg_sort(
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+
+    #[test]
+    fn test_argsort_unstable() {
+        let s = Series::new("a".into(), [Some(3), None, Some(1), Some(2), None]);
+
+        let idx = s.argsort_unstable(SortOptions::default());
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(1), Some(4), Some(2), Some(3), Some(0)]
+        );
+
+        let idx = s.argsort_unstable(
+            SortOptions::default()
+                .with_order_descending(true)
+                .with_nulls_last(true),
+        );
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(0), Some(3), Some(2), Some(1), Some(4)]
+        );
+    }
+
     #[test]
     fn test_arg_sort() {
         let a = Int32Chunked::new(
@@ -0,0 +1,86 @@
+use arrow::bitmap::MutableBitmap;
+
+use crate::prelude::*;
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Convert a sparse `(index, value)` representation into a dense array of length `len`.
+    ///
+    /// Every position that isn't in `indices` is set to `fill_value`. Null values are written as
+    /// null, and if an index occurs more than once the last value wins. The result takes the name
+    /// of `values`.
+    pub fn sparse_to_dense(
+        indices: &IdxCa,
+        values: &ChunkedArray<T>,
+        len: usize,
+        fill_value: T::Native,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            indices.len() == values.len(),
+            length_mismatch = "sparse_to_dense",
+            indices.len(),
+            values.len()
+        );
+        polars_ensure!(
+            !indices.has_nulls(),
+            ComputeError: "'sparse_to_dense' does not support null indices"
+        );
+
+        let mut out = vec![fill_value; len];
+        let mut validity: Option<MutableBitmap> = None;
+        for (idx, opt_v) in indices.into_no_null_iter().zip(values.iter()) {
+            let idx = idx as usize;
+            polars_ensure!(idx < len, oob = idx, len);
+            match opt_v {
+                Some(v) => {
+                    out[idx] = v;
+                    if let Some(validity) = &mut validity {
+                        validity.set(idx, true);
+                    }
+                },
+                None => validity
+                    .get_or_insert_with(|| {
+                        let mut validity = MutableBitmap::with_capacity(len);
+                        validity.extend_constant(len, true);
+                        validity
+                    })
+                    .set(idx, false),
+            }
+        }
+
+        Ok(Self::from_vec_validity(
+            values.name().clone(),
+            out,
+            validity.map(|v| v.freeze()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sparse_to_dense() -> PolarsResult<()> {
+        let indices = IdxCa::from_slice("idx".into(), &[4, 1, 3, 1]);
+        let values = Int32Chunked::new("v".into(), &[Some(10), Some(20), None, Some(30)]);
+
+        let out = Int32Chunked::sparse_to_dense(&indices, &values, 6, -1)?;
+        assert_eq!(out.name().as_str(), "v");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(-1), Some(30), Some(-1), None, Some(10), Some(-1)]
+        );
+
+        let values = Int32Chunked::from_slice("v".into(), &[1, 2, 3, 4]);
+        let out = Int32Chunked::sparse_to_dense(&indices, &values, 5, 0)?;
+        assert!(!out.has_nulls());
+        assert_eq!(out.cont_slice()?, &[0, 4, 0, 3, 1]);
+
+        assert!(Int32Chunked::sparse_to_dense(&indices, &values, 4, 0).is_err());
+        assert!(Int32Chunked::sparse_to_dense(&indices, &values.slice(0, 2), 6, 0).is_err());
+        Ok(())
+    }
+}
@@ -162,6 +162,43 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Array: for<'a> IfThenElseKernel<Scalar<'a> = T::Native>,
+{
+    /// Take the values of `self` where `mask` is `true` and `scalar` elsewhere.
+    ///
+    /// Contrary to [`ChunkZip::zip_with`] this doesn't need `scalar` to be
+    /// broadcasted to a full [`ChunkedArray`] first. Null values in `mask` are
+    /// treated as `false`.
+    pub fn zip_with_scalar(
+        &self,
+        mask: &BooleanChunked,
+        scalar: T::Native,
+    ) -> PolarsResult<ChunkedArray<T>> {
+        // Broadcast mask.
+        if mask.len() == 1 {
+            return Ok(if mask.get(0).unwrap_or(false) {
+                self.clone()
+            } else {
+                ChunkedArray::full(self.name().clone(), scalar, self.len())
+            });
+        }
+        polars_ensure!(mask.len() == self.len(), ShapeMismatch: SHAPE_MISMATCH_STR);
+
+        let (mask_al, if_true_al) = align_chunks_binary(mask, self);
+        let chunks = mask_al
+            .downcast_iter()
+            .zip(if_true_al.downcast_iter())
+            .map(|(m, t)| {
+                let bm = bool_null_to_false(m);
+                IfThenElseKernel::if_then_else_broadcast_false(&bm, t, scalar)
+            });
+        Ok(ChunkedArray::from_chunk_iter_like(self, chunks))
+    }
+}
+
 // Basic implementation for ObjectArray.
 #[cfg(feature = "object")]
 impl<T: PolarsObject> IfThenElseKernel for ObjectArray<T> {
@@ -533,3 +570,25 @@ impl ChunkZip<StructType> for StructChunked {
         Ok(out)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_zip_with_scalar() -> PolarsResult<()> {
+        let ca = Int32Chunked::new("a".into(), &[Some(1), None, Some(3), Some(4)]);
+        let mask = BooleanChunked::new("m".into(), &[Some(true), Some(true), None, Some(false)]);
+
+        let out = ca.zip_with_scalar(&mask, 10)?;
+        assert_eq!(Vec::from(&out), &[Some(1), None, Some(10), Some(10)]);
+        assert_eq!(out.name().as_str(), "a");
+
+        let out = ca.zip_with_scalar(&BooleanChunked::new("m".into(), &[false]), 0)?;
+        assert_eq!(Vec::from(&out), &[Some(0); 4]);
+
+        let mask = BooleanChunked::new("m".into(), &[true]);
+        assert!(ca.zip_with_scalar(&mask.new_from_index(0, 2), 0).is_err());
+        Ok(())
+    }
+}
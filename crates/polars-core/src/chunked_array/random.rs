@@ -214,6 +214,60 @@ impl DataFrame {
         Ok(unsafe { self.take_unchecked(&idx) })
     }
 
+    /// Sample `n` rows from every group of `by`.
+    ///
+    /// If `n` is smaller than the number of groups, `n` is instead the total number of rows: every
+    /// group gets `n / n_groups` rows and the remaining `n % n_groups` rows are taken one each from
+    /// the first groups.
+    ///
+    /// The samples are returned group by group, in the order in which the groups first occur.
+    /// Without replacement every group must have at least as many rows as are sampled from it.
+    /// With a `seed`, every group is sampled with a distinct seed derived from it.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn sample_stratified(
+        &self,
+        by: &[PlSmallStr],
+        n: usize,
+        seed: Option<u64>,
+        with_replacement: bool,
+    ) -> PolarsResult<Self> {
+        let gb = self.group_by_stable(by.iter().cloned())?;
+        let groups = gb.get_groups();
+        let n_groups = groups.len();
+        let (per_group, remainder) = if n < n_groups {
+            (n / n_groups, n % n_groups)
+        } else {
+            (n, 0)
+        };
+
+        let mut idx: Vec<IdxSize> =
+            Vec::with_capacity(n_groups.saturating_mul(per_group) + remainder);
+        for (i, g) in groups.iter().enumerate() {
+            let n = per_group + (i < remainder) as usize;
+            let len = g.len();
+            polars_ensure!(
+                with_replacement || n <= len,
+                ShapeMismatch: "cannot take a sample of {} rows from a group of {} rows when \
+                `with_replacement=false`", n, len
+            );
+            let seed = seed.map(|seed| seed.wrapping_add(i as u64));
+            let local = match with_replacement {
+                true => create_rand_index_with_replacement(n, len, seed),
+                false => create_rand_index_no_replacement(n, len, seed, false),
+            };
+            let local = local.cont_slice().unwrap();
+            match g {
+                GroupsIndicator::Idx((_, group)) => {
+                    idx.extend(local.iter().map(|&j| group[j as usize]))
+                },
+                GroupsIndicator::Slice([first, _]) => idx.extend(local.iter().map(|&j| first + j)),
+            }
+        }
+
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self._take_unchecked_slice(&idx, true) })
+    }
+
     /// Sample a fraction between 0.0-1.0 of this [`DataFrame`].
     pub fn sample_frac(
         &self,
@@ -391,4 +445,40 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_sample_stratified() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a", "c", "c"],
+            "x" => [1, 2, 3, 4, 5, 6, 7]
+        ]?;
+        let by = [PlSmallStr::from_static("g")];
+        let groups = |out: &DataFrame| -> PolarsResult<Vec<Option<String>>> {
+            Ok(out
+                .column("g")?
+                .str()?
+                .into_iter()
+                .map(|g| g.map(str::to_string))
+                .collect())
+        };
+        let expected = |g: &[&str]| g.iter().map(|g| Some(g.to_string())).collect::<Vec<_>>();
+
+        let out = df.head(Some(4)).sample_stratified(&by, 2, Some(0), false)?;
+        assert_eq!(groups(&out)?, expected(&["a", "a", "b", "b"]));
+
+        assert!(df.sample_stratified(&by, 3, Some(0), false).is_err());
+        let out = df.sample_stratified(&by, 3, Some(0), true)?;
+        assert_eq!(
+            groups(&out)?,
+            expected(&["a", "a", "a", "b", "b", "b", "c", "c", "c"])
+        );
+
+        // With fewer rows than groups, `n` is the total and is spread over the groups in order.
+        let out = df.sample_stratified(&by, 2, Some(0), false)?;
+        assert_eq!(groups(&out)?, expected(&["a", "b"]));
+        let out = df.sample_stratified(&by, 0, Some(0), false)?;
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
 }
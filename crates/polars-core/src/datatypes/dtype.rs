@@ -13,7 +13,7 @@ pub use temporal::time_zone::TimeZone;
 use super::*;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::registry::get_object_physical_type;
-use crate::utils::materialize_dyn_int;
+use crate::utils::{dtypes_to_supertype, materialize_dyn_int};
 
 static MAINTAIN_PL_TYPE: &str = "maintain_type";
 static PL_KEY: &str = "pl";
@@ -228,6 +228,15 @@ impl DataType {
         }
     }
 
+    /// Determine the data type that all `dtypes` can safely be cast to.
+    ///
+    /// Folds over `dtypes` with [`try_get_supertype`](crate::utils::try_get_supertype)
+    /// and returns a [`PolarsError::SchemaMismatch`] if no common supertype exists.
+    /// An empty slice yields [`DataType::Null`].
+    pub fn supertype_many(dtypes: &[DataType]) -> PolarsResult<DataType> {
+        dtypes_to_supertype(dtypes)
+    }
+
     /// Check if the whole dtype is known.
     pub fn is_known(&self) -> bool {
         match self {
@@ -1252,4 +1261,17 @@ mod tests {
 
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn test_supertype_many() {
+        let dtypes = [DataType::Int32, DataType::Null, DataType::Float64];
+        assert_eq!(
+            DataType::supertype_many(&dtypes).unwrap(),
+            DataType::Float64
+        );
+        assert_eq!(DataType::supertype_many(&[]).unwrap(), DataType::Null);
+
+        let dtypes = [DataType::Int32, DataType::List(Box::new(DataType::Int32))];
+        assert!(DataType::supertype_many(&dtypes).is_err());
+    }
 }
@@ -26,6 +26,17 @@ pub struct UnpivotArgsIR {
     pub index: Vec<PlSmallStr>,
     pub variable_name: Option<PlSmallStr>,
     pub value_name: Option<PlSmallStr>,
+    /// Prefix that is prepended to the names in the variable column.
+    pub variable_column_prefix: Option<PlSmallStr>,
+}
+
+impl UnpivotArgsIR {
+    /// Prepend `prefix` to the names in the variable column, e.g. to turn the column names
+    /// `2020` and `2021` into `year_2020` and `year_2021`.
+    pub fn with_variable_column_prefix(mut self, prefix: impl Into<PlSmallStr>) -> Self {
+        self.variable_column_prefix = Some(prefix.into());
+        self
+    }
 }
 
 impl DataFrame {
@@ -192,6 +203,49 @@ impl DataFrame {
         let columns = self.select_columns(columns)?;
         self.explode_impl(columns)
     }
+
+    /// Explode all `cols` together, which must be `List` columns with the same number of
+    /// elements in every row.
+    ///
+    /// Unlike [`DataFrame::explode`], the element counts are validated up front and an error
+    /// lists every column that doesn't match the first one. Null and empty lists count as one
+    /// element, as they are exploded to a single null.
+    pub fn flatten_list_columns(&self, cols: &[PlSmallStr]) -> PolarsResult<DataFrame> {
+        let columns = self.select_columns_impl(cols)?;
+        polars_ensure!(
+            !columns.is_empty(),
+            InvalidOperation: "no columns provided in 'flatten_list_columns'"
+        );
+
+        let element_counts = |c: &Column| -> PolarsResult<Vec<usize>> {
+            let ca = c.list()?;
+            let mut counts = Vec::with_capacity(ca.len());
+            for arr in ca.downcast_iter() {
+                counts.extend(
+                    arr.offsets()
+                        .lengths()
+                        .enumerate()
+                        .map(|(i, len)| if arr.is_null(i) { 1 } else { len.max(1) }),
+                );
+            }
+            Ok(counts)
+        };
+
+        let first = element_counts(&columns[0])?;
+        let mut mismatched = vec![];
+        for c in &columns[1..] {
+            if element_counts(c)? != first {
+                mismatched.push(c.name().as_str());
+            }
+        }
+        polars_ensure!(
+            mismatched.is_empty(),
+            ShapeMismatch: "columns {:?} don't have the same element counts as column '{}'",
+            mismatched, columns[0].name()
+        );
+
+        self.explode_impl(columns)
+    }
 }
 
 #[cfg(test)]
@@ -244,6 +298,33 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_flatten_list_columns() -> PolarsResult<()> {
+        let a = Series::new("a".into(), &[1, 2]);
+        let b = Series::new("b".into(), &[3]);
+        let df = DataFrame::new(vec![
+            Column::new("x".into(), &[a.clone(), b.clone()]),
+            Column::new("y".into(), &[b.clone(), a.clone()]),
+            Column::new("z".into(), &[a, b]),
+            Column::new("k".into(), [1, 2]),
+        ])?;
+
+        let out = df.flatten_list_columns(&["x".into(), "z".into()])?;
+        assert_eq!(out.shape(), (3, 4));
+        assert_eq!(
+            Vec::from(out.column("k")?.i32()?),
+            &[Some(1), Some(1), Some(2)]
+        );
+
+        let err = df
+            .flatten_list_columns(&["x".into(), "y".into()])
+            .unwrap_err();
+        assert!(err.to_string().contains("\"y\""));
+        assert!(df.flatten_list_columns(&["k".into()]).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_explode_df_empty_list() -> PolarsResult<()> {
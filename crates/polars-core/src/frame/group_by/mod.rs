@@ -17,9 +17,11 @@ pub mod expr;
 pub(crate) mod hashing;
 mod into_groups;
 mod position;
+mod state;
 
 pub use into_groups::*;
 pub use position::*;
+pub use state::{GroupState, MeanState};
 
 use crate::chunked_array::ops::row_encode::{
     encode_rows_unordered, encode_rows_vertical_par_unordered,
@@ -654,6 +656,43 @@ impl<'a> GroupBy<'a> {
         DataFrame::new(cols)
     }
 
+    /// Aggregate grouped [`Series`] and determine the variance per group with Welford's
+    /// single-pass algorithm.
+    ///
+    /// This avoids the loss of precision of the sum-of-squares formulation for values that are
+    /// large compared to their variance. Nulls are ignored; groups with `ddof` or less non-null
+    /// values are null.
+    pub fn var_stable(&self, ddof: u8) -> PolarsResult<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            polars_ensure!(
+                agg_col.dtype().is_primitive_numeric(),
+                InvalidOperation: "'var_stable' operation not supported for dtype `{}`", agg_col.dtype()
+            );
+            let values = agg_col.cast(&DataType::Float64)?;
+            let values = values.f64()?.rechunk();
+
+            // SAFETY: the group indices are in bounds.
+            let get = |i: IdxSize| unsafe { values.get_unchecked(i as usize) };
+            let out: Float64Chunked = self
+                .groups
+                .iter()
+                .map(|g| match g {
+                    GroupsIndicator::Idx((_, idx)) => {
+                        welford_var(idx.iter().map(|&i| get(i)), ddof)
+                    },
+                    GroupsIndicator::Slice([first, len]) => {
+                        welford_var((first..first + len).map(get), ddof)
+                    },
+                })
+                .collect();
+
+            let new_name = fmt_group_by_column(agg_col.name().as_str(), GroupByMethod::Var(ddof));
+            cols.push(out.with_name(new_name).into_column());
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped [`Series`] and determine the standard deviation per group.
     #[deprecated(since = "0.24.1", note = "use polars.lazy aggregations")]
     pub fn std(&self, ddof: u8) -> PolarsResult<DataFrame> {
@@ -821,6 +860,180 @@ impl<'a> GroupBy<'a> {
         Ok(df)
     }
 
+    /// Apply a closure over the groups in parallel on the thread pool and concatenate the results
+    /// vertically.
+    ///
+    /// All results must have the same schema. If there are no groups, `f` is called once on the
+    /// empty [`DataFrame`] to get the schema of the output.
+    pub fn apply_parallel<F>(&self, f: F) -> PolarsResult<DataFrame>
+    where
+        F: Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        if self.df.height() == 0 {
+            return f(self.df.clear());
+        }
+
+        let df = self.prepare_apply()?;
+        let dfs = POOL.install(|| {
+            self.get_groups()
+                .par_iter()
+                .map(|g| {
+                    // SAFETY:
+                    // groups are in bounds
+                    let sub_df = unsafe { take_df(&df, g) };
+                    f(sub_df)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        if let Some((first, rest)) = dfs.split_first() {
+            for out in rest {
+                polars_ensure!(
+                    out.schema() == first.schema(),
+                    SchemaMismatch: "'apply_parallel' results must have the same schema, got:\n{:?}\nand\n{:?}",
+                    first.schema(), out.schema()
+                );
+            }
+        }
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        df.as_single_chunk_par();
+        Ok(df)
+    }
+
+    /// Take the first `n` rows of every group.
+    ///
+    /// Groups with less than `n` rows are returned in full. The rows are returned group by group,
+    /// in the order of the groups.
+    pub fn head(&self, n: usize) -> PolarsResult<DataFrame> {
+        self.head_tail(n, false)
+    }
+
+    /// Take the last `n` rows of every group.
+    ///
+    /// Groups with less than `n` rows are returned in full. The rows are returned group by group,
+    /// in the order of the groups.
+    pub fn tail(&self, n: usize) -> PolarsResult<DataFrame> {
+        self.head_tail(n, true)
+    }
+
+    fn head_tail(&self, n: usize, tail: bool) -> PolarsResult<DataFrame> {
+        if self.df.height() == 0 {
+            return Ok(self.df.clear());
+        }
+        let df = self.prepare_apply()?;
+
+        let capacity = self.groups.len().saturating_mul(n).min(df.height());
+        let mut idx: Vec<IdxSize> = Vec::with_capacity(capacity);
+        match &*self.groups {
+            GroupsType::Idx(groups) => {
+                for (_, group) in groups {
+                    let take = n.min(group.len());
+                    let offset = if tail { group.len() - take } else { 0 };
+                    idx.extend_from_slice(&group[offset..offset + take]);
+                }
+            },
+            GroupsType::Slice { groups, .. } => {
+                for &[first, len] in groups {
+                    let take = (n as IdxSize).min(len);
+                    let offset = if tail { first + len - take } else { first };
+                    idx.extend(offset..offset + take);
+                }
+            },
+        }
+
+        // SAFETY: the group indices are in bounds.
+        Ok(unsafe { df._take_unchecked_slice(&idx, true) })
+    }
+
+    /// Aggregate the groups with `agg` and broadcast the aggregated values back to the rows of
+    /// the original [`DataFrame`].
+    ///
+    /// `agg` must return one row per group, in the order of the groups, as the aggregation
+    /// methods on [`GroupBy`] do. The group-key columns are dropped from the result, which has
+    /// the same height as the original [`DataFrame`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.group_by(["date"])?.select(["temp"]).transform(|gb| gb.mean())
+    /// }
+    /// ```
+    pub fn transform<F>(&self, agg: F) -> PolarsResult<DataFrame>
+    where
+        F: FnOnce(&GroupBy) -> PolarsResult<DataFrame>,
+    {
+        let aggregated = agg(self)?;
+        polars_ensure!(
+            aggregated.height() == self.groups.len(),
+            ShapeMismatch: "'transform' expected one row per group ({}), got {}",
+            self.groups.len(), aggregated.height()
+        );
+        let aggregated = aggregated.drop_many(self.selected_keys.iter().map(|c| c.name().clone()));
+
+        // Map every row of the original frame to the group it belongs to.
+        let height = self.df.height();
+        let mut idx: Vec<IdxSize> = vec![IdxSize::MAX; height];
+        let mut covered = 0usize;
+        for (group_idx, group) in self.groups.iter().enumerate() {
+            let group_idx = group_idx as IdxSize;
+            match group {
+                GroupsIndicator::Idx((_, group)) => {
+                    for &i in group.iter() {
+                        idx[i as usize] = group_idx;
+                    }
+                    covered += group.len();
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    idx[first as usize..(first + len) as usize].fill(group_idx);
+                    covered += len as usize;
+                },
+            }
+        }
+        polars_ensure!(
+            covered == height,
+            ComputeError: "'transform' requires every row to belong to a group"
+        );
+
+        // SAFETY: every row is mapped to a valid group index.
+        Ok(unsafe { aggregated._take_unchecked_slice(&idx, true) })
+    }
+
+    /// Aggregate the groups with `agg` in batches of `chunk_size` groups.
+    ///
+    /// The groups are visited in the order of their first row, so the batches follow the row
+    /// (and thus chunk) order of the [`DataFrame`] and can be processed downstream as soon as
+    /// they are produced.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    pub fn aggregate_chunked<'b, F>(
+        &'b self,
+        agg: F,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = PolarsResult<DataFrame>> + 'b
+    where
+        F: Fn(&GroupBy<'a>) -> PolarsResult<DataFrame> + 'b,
+    {
+        assert!(chunk_size > 0, "'chunk_size' must be greater than zero");
+        let mut groups = self.groups.clone();
+        groups.sort();
+
+        let n_groups = groups.len();
+        (0..n_groups).step_by(chunk_size).map(move |offset| {
+            let len = chunk_size.min(n_groups - offset);
+            let gb = GroupBy::new(
+                self.df,
+                self.selected_keys.clone(),
+                groups.slice(offset as i64, len),
+                self.selected_agg.clone(),
+            );
+            agg(&gb)
+        })
+    }
+
     /// Apply a closure over the groups as a new [`DataFrame`].
     pub fn apply<F>(&self, mut f: F) -> PolarsResult<DataFrame>
     where
@@ -855,6 +1068,20 @@ impl<'a> GroupBy<'a> {
     }
 }
 
+/// Welford's online algorithm for the variance, ignoring nulls.
+fn welford_var(values: impl Iterator<Item = Option<f64>>, ddof: u8) -> Option<f64> {
+    let mut n = 0u64;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    for v in values.flatten() {
+        n += 1;
+        let delta = v - mean;
+        mean += delta / n as f64;
+        m2 += delta * (v - mean);
+    }
+    (n > ddof as u64).then(|| m2 / (n - ddof as u64) as f64)
+}
+
 unsafe fn take_df(df: &DataFrame, g: GroupsIndicator) -> DataFrame {
     match g {
         GroupsIndicator::Idx(idx) => df.take_slice_unchecked(idx.1),
@@ -1186,6 +1413,185 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_var_stable() -> PolarsResult<()> {
+        // Wilkinson-style test: a large offset with a small variance.
+        let offset = 1e9;
+        let df = df![
+            "g" => [1, 1, 1, 1, 2, 3, 3],
+            "x" => [
+                Some(offset + 4.0),
+                Some(offset + 7.0),
+                Some(offset + 13.0),
+                Some(offset + 16.0),
+                Some(1.0),
+                Some(2.0),
+                None
+            ]
+        ]?;
+
+        let out = df.group_by_stable(["g"])?.var_stable(1)?;
+        let var = out.column("x_agg_var")?.f64()?;
+        assert_eq!(var.get(0), Some(30.0));
+        assert_eq!(var.get(1), None);
+        assert_eq!(var.get(2), None);
+
+        let out = df.group_by_stable(["g"])?.var_stable(0)?;
+        let var = out.column("x_agg_var")?.f64()?;
+        assert_eq!(var.get(0), Some(22.5));
+        assert_eq!(var.get(1), Some(0.0));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_var_stable_precision() -> PolarsResult<()> {
+        // The values cycle through 0..10 on top of the offset, so the exact population variance
+        // is 8.25 for every offset.
+        let n = 10_000;
+        for offset in [0.0, 1e6, 1e9, 1e12] {
+            let x = (0..n).map(|i| offset + (i % 10) as f64).collect::<Vec<_>>();
+            let df = df![
+                "g" => vec![1; n],
+                "x" => &x
+            ]?;
+            let out = df.group_by_stable(["g"])?.var_stable(0)?;
+            let var = out.column("x_agg_var")?.f64()?.get(0).unwrap();
+            assert!((var - 8.25).abs() < 1e-6, "offset {offset}: {var}");
+
+            // The sum-of-squares formulation cancels catastrophically for large offsets.
+            let mean = x.iter().sum::<f64>() / n as f64;
+            let naive = x.iter().map(|v| v * v).sum::<f64>() / n as f64 - mean * mean;
+            if offset >= 1e9 {
+                assert!((naive - 8.25).abs() > 1.0, "offset {offset}: {naive}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_head_tail() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "a", "b"],
+            "x" => [1, 2, 3, 4, 5, 6]
+        ]?;
+
+        let gb = df.group_by_stable(["g"])?;
+        let out = gb.head(2)?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(1), Some(3), Some(2), Some(6), Some(4)]
+        );
+        let out = gb.tail(1)?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(5), Some(6), Some(4)]
+        );
+
+        // Sorted keys produce slice groups.
+        let mut g = Series::new("g".into(), [1, 1, 1, 2, 3, 3]);
+        g.set_sorted_flag(crate::series::IsSorted::Ascending);
+        let df = DataFrame::new(vec![g.into(), Column::new("x".into(), [1, 2, 3, 4, 5, 6])])?;
+        let gb = df.group_by_stable(["g"])?;
+        assert!(matches!(&**gb.get_groups(), GroupsType::Slice { .. }));
+        let out = gb.tail(2)?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(2), Some(3), Some(4), Some(5), Some(6)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_transform() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "b"],
+            "x" => [1, 2, 3, 4, 5]
+        ]?;
+
+        // Use of deprecated `sum()` for testing purposes
+        #[allow(deprecated)]
+        let out = df.group_by(["g"])?.select(["x"]).transform(|gb| gb.sum())?;
+        assert_eq!(out.get_column_names(), &["x_sum"]);
+        assert_eq!(
+            Vec::from(out.column("x_sum")?.i32()?),
+            &[Some(4), Some(7), Some(4), Some(4), Some(7)]
+        );
+
+        let out = df
+            .group_by(["g"])?
+            .transform(|gb| gb.head(1).map(|df| df.head(Some(1))));
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_aggregate_chunked() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["c", "a", "c", "b", "a", "d", "e"],
+            "x" => [1, 2, 3, 4, 5, 6, 7]
+        ]?;
+
+        let gb = df.group_by(["g"])?;
+        // Use of deprecated `sum()` for testing purposes
+        #[allow(deprecated)]
+        let chunks = gb
+            .aggregate_chunked(|gb| gb.clone().select(["x"]).sum(), 2)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(
+            chunks.iter().map(|df| df.height()).collect::<Vec<_>>(),
+            &[2, 2, 1]
+        );
+
+        let out = crate::utils::accumulate_dataframes_vertical(chunks)?;
+        assert_eq!(
+            Vec::from(out.column("g")?.str()?),
+            &[Some("c"), Some("a"), Some("b"), Some("d"), Some("e")]
+        );
+        assert_eq!(
+            Vec::from(out.column("x_sum")?.i32()?),
+            &[Some(4), Some(7), Some(4), Some(6), Some(7)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_apply_parallel() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "b"],
+            "x" => [1, 2, 3, 4, 5]
+        ]?;
+
+        let out = df
+            .group_by_stable(["g"])?
+            .apply_parallel(|df| df.head(Some(1)).select(["x"]))?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(1), Some(2), Some(4)]
+        );
+
+        let out = df
+            .clear()
+            .group_by(["g"])?
+            .apply_parallel(|df| df.select(["x"]))?;
+        assert_eq!(out.shape(), (0, 1));
+
+        let out = df.group_by_stable(["g"])?.apply_parallel(|df| {
+            if df.height() == 1 {
+                df.select(["g"])
+            } else {
+                df.select(["x"])
+            }
+        });
+        assert!(out.is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[cfg(feature = "dtype-categorical")]
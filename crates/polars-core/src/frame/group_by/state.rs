@@ -0,0 +1,121 @@
+use super::*;
+
+/// An accumulator that is fed the rows of a single group one at a time.
+///
+/// See [`GroupBy::agg_with_state`].
+pub trait GroupState: Send + Default {
+    /// Add a row, holding the values of the aggregated columns in order.
+    fn update(&mut self, row: &[AnyValue<'_>]);
+
+    /// The aggregated value of all rows seen so far.
+    fn finalize(&self) -> AnyValue<'static>;
+}
+
+/// A [`GroupState`] computing the mean of the first aggregated column, ignoring nulls.
+#[derive(Debug, Default, Clone)]
+pub struct MeanState {
+    sum: f64,
+    count: usize,
+}
+
+impl GroupState for MeanState {
+    fn update(&mut self, row: &[AnyValue<'_>]) {
+        if let Some(v) = row.first().and_then(|v| v.extract::<f64>()) {
+            self.sum += v;
+            self.count += 1;
+        }
+    }
+
+    fn finalize(&self) -> AnyValue<'static> {
+        if self.count == 0 {
+            AnyValue::Null
+        } else {
+            AnyValue::Float64(self.sum / self.count as f64)
+        }
+    }
+}
+
+impl GroupBy<'_> {
+    /// Aggregate every group by pushing its rows one by one into a fresh `S`.
+    ///
+    /// The rows passed to [`GroupState::update`] hold the selected aggregation columns (or all
+    /// non-key columns if none were selected). The output has the group keys and a single `state`
+    /// column with the finalized value of every group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     df.group_by(["date"])?.select(["temp"]).agg_with_state::<MeanState>()
+    /// }
+    /// ```
+    pub fn agg_with_state<S: GroupState>(&self) -> PolarsResult<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+
+        let mut row = Vec::with_capacity(agg_cols.len());
+        let mut push_row = |state: &mut S, i: usize| {
+            row.clear();
+            // SAFETY: group indices are in bounds of the frame.
+            row.extend(agg_cols.iter().map(|c| unsafe { c.get_unchecked(i) }));
+            state.update(&row);
+        };
+
+        let values = self
+            .groups
+            .iter()
+            .map(|group| {
+                let mut state = S::default();
+                match group {
+                    GroupsIndicator::Idx((_, idx)) => {
+                        for &i in idx.iter() {
+                            push_row(&mut state, i as usize);
+                        }
+                    },
+                    GroupsIndicator::Slice([first, len]) => {
+                        for i in first..first + len {
+                            push_row(&mut state, i as usize);
+                        }
+                    },
+                }
+                state.finalize()
+            })
+            .collect::<Vec<_>>();
+
+        let state = Series::from_any_values(PlSmallStr::from_static("state"), &values, true)?;
+        cols.push(state.into_column());
+        DataFrame::new(cols)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_group_by_agg_with_state() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "b", "a"],
+            "x" => [Some(1), Some(2), Some(3), None, Some(6), None]
+        ]?;
+
+        for gb in [df.group_by_stable(["g"])?, df.group_by(["g"])?] {
+            let gb = gb.select(["x"]);
+            let out = gb.agg_with_state::<MeanState>()?;
+            let expected = gb.mean()?;
+            assert_eq!(
+                Vec::from(out.column("state")?.f64()?),
+                Vec::from(expected.column("x_mean")?.f64()?)
+            );
+            assert!(out.column("g")?.equals(expected.column("g")?));
+        }
+
+        let out = df.group_by_stable(["g"])?.agg_with_state::<MeanState>()?;
+        assert_eq!(
+            Vec::from(out.column("state")?.f64()?),
+            &[Some(2.0), Some(4.0), None]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use arrow::array::BinaryArray;
+
+use super::*;
+use crate::chunked_array::ops::row_encode::_get_rows_encoded_ca;
+use crate::chunked_array::ops::sort::_broadcast_bools;
+use crate::series::IsSorted;
+
+fn ensure_sorted(rows: &BinaryArray<i64>, side: &str) -> PolarsResult<()> {
+    let is_sorted = rows
+        .values_iter()
+        .zip(rows.values_iter().skip(1))
+        .all(|(prev, next)| prev <= next);
+    polars_ensure!(
+        is_sorted,
+        InvalidOperation: "'merge_sorted' requires the {} frame to be sorted by the key columns in the given order",
+        side
+    );
+    Ok(())
+}
+
+impl DataFrame {
+    /// Merge two [`DataFrame`]s that are both sorted by `key` into a single sorted [`DataFrame`].
+    ///
+    /// Both frames must be sorted according to the `descending` and `nulls_last` fields of
+    /// `options`, which is verified before merging. This is a single O(n + m) pass over the key
+    /// columns instead of a concatenation followed by a full sort. Rows with equal keys keep their
+    /// order, with the rows of `left` first.
+    pub fn merge_sorted(
+        left: DataFrame,
+        right: DataFrame,
+        key: &[PlSmallStr],
+        options: SortMultipleOptions,
+    ) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            !key.is_empty(),
+            ComputeError: "'merge_sorted' requires at least one key column"
+        );
+        let mut descending = options.descending;
+        let mut nulls_last = options.nulls_last;
+        _broadcast_bools(key.len(), &mut descending);
+        _broadcast_bools(key.len(), &mut nulls_last);
+        polars_ensure!(
+            descending.len() == key.len() && nulls_last.len() == key.len(),
+            ComputeError: "the length of `descending` and `nulls_last` ({}, {}) does not match the number of key columns ({})",
+            descending.len(), nulls_last.len(), key.len()
+        );
+        let left_by = left.select_columns_impl(key)?;
+        let right_by = right.select_columns_impl(key)?;
+
+        // The row encoding orders the rows as bytes in the requested sort order.
+        let left_rows =
+            _get_rows_encoded_ca(PlSmallStr::EMPTY, &left_by, &descending, &nulls_last)?;
+        let right_rows =
+            _get_rows_encoded_ca(PlSmallStr::EMPTY, &right_by, &descending, &nulls_last)?;
+        let left_rows = left_rows.downcast_as_array();
+        let right_rows = right_rows.downcast_as_array();
+        ensure_sorted(left_rows, "left")?;
+        ensure_sorted(right_rows, "right")?;
+
+        let left_height = left.height();
+        let right_height = right.height();
+        if right_height == 0 || left_height == 0 {
+            let mut out = left;
+            out.vstack_mut_owned(right)?;
+            return Ok(out);
+        }
+
+        let mut idx: Vec<IdxSize> = Vec::with_capacity(left_height + right_height);
+        let (mut i, mut j) = (0, 0);
+        while i < left_height && j < right_height {
+            // Only take from the right if it is strictly smaller, which keeps the merge stable.
+            if right_rows.value(j) < left_rows.value(i) {
+                idx.push((left_height + j) as IdxSize);
+                j += 1;
+            } else {
+                idx.push(i as IdxSize);
+                i += 1;
+            }
+        }
+        idx.extend((i..left_height).map(|i| i as IdxSize));
+        idx.extend((left_height + j..left_height + right_height).map(|i| i as IdxSize));
+
+        let mut stacked = left;
+        stacked.vstack_mut_owned(right)?;
+        // SAFETY: every index is smaller than the height of the stacked frame.
+        let mut out = unsafe { stacked.take_slice_unchecked(&idx) };
+
+        let sorted = if descending[0] {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        };
+        out.apply(key[0].as_str(), |s| {
+            let mut s = s.clone();
+            s.set_sorted_flag(sorted);
+            s
+        })?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::series::IsSorted;
+
+    #[test]
+    fn test_merge_sorted() -> PolarsResult<()> {
+        let left = df![
+            "a" => [Some(1), Some(1), Some(3), Some(5)],
+            "b" => ["x", "y", "x", "x"],
+            "side" => ["l0", "l1", "l2", "l3"]
+        ]?;
+        let right = df![
+            "a" => [None, Some(1), Some(4)],
+            "b" => ["x", "x", "x"],
+            "side" => ["r0", "r1", "r2"]
+        ]?;
+
+        let opts = SortMultipleOptions::default();
+        let out =
+            DataFrame::merge_sorted(left.clone(), right.clone(), &["a".into()], opts.clone())?;
+        assert_eq!(
+            Vec::from(out.column("side")?.str()?),
+            &[
+                Some("r0"),
+                Some("l0"),
+                Some("l1"),
+                Some("r1"),
+                Some("l2"),
+                Some("r2"),
+                Some("l3")
+            ]
+        );
+
+        let out = DataFrame::merge_sorted(
+            left.clone(),
+            right.clone(),
+            &["a".into(), "b".into()],
+            opts.clone(),
+        )?;
+        assert_eq!(
+            Vec::from(out.column("side")?.str()?),
+            &[
+                Some("r0"),
+                Some("l0"),
+                Some("r1"),
+                Some("l1"),
+                Some("l2"),
+                Some("r2"),
+                Some("l3")
+            ]
+        );
+
+        let out = DataFrame::merge_sorted(left.clear(), left.clone(), &["a".into()], opts.clone())?;
+        assert!(out.equals(&left));
+
+        // Inputs that are not sorted in the requested order are rejected.
+        let unsorted = left.reverse();
+        assert!(
+            DataFrame::merge_sorted(unsorted.clone(), right.clone(), &["a".into()], opts).is_err()
+        );
+
+        let desc = SortMultipleOptions::default()
+            .with_order_descending(true)
+            .with_nulls_last(true);
+        let right = right.sort(["a"], desc.clone())?;
+        let out = DataFrame::merge_sorted(unsorted, right, &["a".into()], desc)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(5), Some(4), Some(3), Some(1), Some(1), Some(1), None]
+        );
+        assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Descending);
+        Ok(())
+    }
+}
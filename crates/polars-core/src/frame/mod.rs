@@ -6,10 +6,13 @@ use std::{mem, ops};
 use arrow::datatypes::ArrowSchemaRef;
 use polars_row::ArrayRef;
 use polars_schema::schema::ensure_matching_schema_names;
+#[cfg(feature = "dtype-struct")]
+use polars_utils::format_pl_smallstr;
 use polars_utils::itertools::Itertools;
 use rayon::prelude::*;
 
 use crate::chunked_array::flags::StatisticsFlags;
+use crate::chunked_array::ops::row_encode::encode_rows_unordered;
 #[cfg(feature = "algorithm_group_by")]
 use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::prelude::*;
@@ -29,6 +32,7 @@ mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 pub(crate) mod horizontal;
+mod merge_sorted;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
@@ -296,11 +300,13 @@ impl DataFrame {
             );
         }
 
-        Ok(DataFrame {
+        let df = DataFrame {
             height,
             columns,
             cached_schema: OnceLock::new(),
-        })
+        };
+        debug_assert!(df.validate_column_name_uniqueness().is_ok());
+        Ok(df)
     }
 
     /// Converts a sequence of columns into a DataFrame, broadcasting length-1
@@ -541,7 +547,9 @@ impl DataFrame {
             DataFrame::validate_columns_slice(&columns).unwrap();
         }
 
-        unsafe { Self::_new_no_checks_impl(height, columns) }
+        let df = unsafe { Self::_new_no_checks_impl(height, columns) };
+        debug_assert!(df.validate_column_name_uniqueness().is_ok());
+        df
     }
 
     /// This will not panic even in debug mode - there are some (rare) use cases where a DataFrame
@@ -1622,6 +1630,44 @@ impl DataFrame {
         unsafe { Some(self.columns.iter().map(|c| c.get_unchecked(idx)).collect()) }
     }
 
+    /// Get the rows for which `predicate` is `true` as vectors of [`AnyValue`]s.
+    ///
+    /// Null values in `predicate` are treated as `false`. If `limit` is set, this stops after
+    /// the first `limit` matching rows. Contrary to [`DataFrame::filter`], this doesn't allocate
+    /// new columns, which makes it cheaper when only a few rows match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> PolarsResult<Vec<Vec<AnyValue>>> {
+    ///     let mask = df.column("a")?.as_materialized_series().gt(10)?;
+    ///     df.get_by_predicate(&mask, Some(1))
+    /// }
+    /// ```
+    pub fn get_by_predicate(
+        &self,
+        predicate: &BooleanChunked,
+        limit: Option<usize>,
+    ) -> PolarsResult<Vec<Vec<AnyValue<'_>>>> {
+        polars_ensure!(
+            predicate.len() == self.height(),
+            ShapeMismatch: "filter's length: {} differs from that of the DataFrame: {}",
+            predicate.len(), self.height()
+        );
+
+        let limit = limit.unwrap_or(usize::MAX);
+        let rows = predicate
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, valid)| (valid == Some(true)).then_some(idx))
+            .take(limit)
+            // SAFETY: `predicate` has the same length as the columns.
+            .map(|idx| unsafe { self.columns.iter().map(|c| c.get_unchecked(idx)).collect() })
+            .collect();
+        Ok(rows)
+    }
+
     /// Select a [`Series`] by index.
     ///
     /// # Example
@@ -1813,6 +1859,26 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(self.height(), selected) })
     }
 
+    /// Select the columns in `schema`, in the order of `schema`.
+    ///
+    /// Columns that are not in `schema` are dropped. Returns an error if a column of `schema`
+    /// is missing from this [`DataFrame`]. The data types are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn example(df: &DataFrame, other: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     // Normalise the column order before concatenating.
+    ///     df.select_ordered(other.schema())
+    /// }
+    /// ```
+    pub fn select_ordered(&self, schema: &Schema) -> PolarsResult<Self> {
+        let cols = schema.iter_names_cloned().collect::<Vec<_>>();
+        // Schema names are unique.
+        self._select_impl_unchecked(&cols)
+    }
+
     /// Select with a known schema. The schema names must match the column names of this DataFrame.
     pub fn select_with_schema<I, S>(&self, selection: I, schema: &SchemaRef) -> PolarsResult<Self>
     where
@@ -2013,6 +2079,47 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(height, new_col) })
     }
 
+    /// Keep only the rows of which the value in column `self_key` occurs in `right_key`, as a
+    /// semi join would.
+    ///
+    /// Only a hash set of the values in `right_key` is built, the join result is never
+    /// materialized. Both keys are compared as their supertype, so e.g. an integer column can be
+    /// filtered by float values. Null values never match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2, 3, 4])?;
+    /// df.semi_join_in_place(&Series::new("b".into(), [4, 2, 5]), "a")?;
+    /// assert_eq!(df.column("a")?.i32()?.to_vec(), &[Some(2), Some(4)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn semi_join_in_place(&mut self, right_key: &Series, self_key: &str) -> PolarsResult<()> {
+        let left = self.column(self_key)?;
+        let dtype = try_get_supertype(left.dtype(), right_key.dtype())?;
+        let right = right_key.strict_cast(&dtype)?.into_column().drop_nulls();
+
+        let right_rows = encode_rows_unordered(&[right])?;
+        let right_rows = right_rows.downcast_as_array();
+        let mut set = PlHashSet::with_capacity(right_rows.len());
+        set.extend(right_rows.values_iter());
+
+        let left_rows = encode_rows_unordered(&[left.strict_cast(&dtype)?])?;
+        let left_valid = left.is_not_null();
+        let mask = BooleanChunked::from_iter_values(
+            PlSmallStr::EMPTY,
+            left_rows
+                .downcast_as_array()
+                .values_iter()
+                .zip(left_valid.into_no_null_iter())
+                .map(|(row, valid)| valid && set.contains(row)),
+        );
+
+        *self = self.filter(&mask)?;
+        Ok(())
+    }
+
     /// Take [`DataFrame`] rows by index values.
     ///
     /// # Example
@@ -2655,6 +2762,82 @@ impl DataFrame {
         self.try_apply_at_idx(idx, |c| f(c.as_materialized_series()))
     }
 
+    /// Apply a closure to every column, replacing them in-place.
+    ///
+    /// The closure must return a column of the same dtype and length as its input. If it
+    /// doesn't for any column, an error listing all of the offending columns is returned and the
+    /// [`DataFrame`] is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2, 3], "b" => [4, 5, 6])?;
+    /// df.map_columns_typed(|c| c.try_add_owned(c.clone()))?;
+    /// assert_eq!(df.column("a")?.i32()?.get(2), Some(6));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn map_columns_typed<F>(&mut self, f: F) -> PolarsResult<()>
+    where
+        F: Fn(&Column) -> PolarsResult<Column>,
+    {
+        let new_columns = self
+            .columns
+            .iter()
+            .map(&f)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let invalid = self
+            .columns
+            .iter()
+            .zip(&new_columns)
+            .filter(|(old, new)| old.dtype() != new.dtype() || old.len() != new.len())
+            .map(|(old, _)| old.name().as_str())
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            invalid.is_empty(),
+            SchemaMismatch: "'map_columns_typed' changed the dtype or length of columns: {}",
+            invalid.join(", ")
+        );
+
+        // Columns may have been renamed, keep the original names.
+        self.columns = self
+            .columns
+            .iter()
+            .zip(new_columns)
+            .map(|(old, new)| new.with_name(old.name().clone()))
+            .collect();
+        self.clear_schema();
+        Ok(())
+    }
+
+    /// Set the rows where `mask` is `true` to null in every column.
+    ///
+    /// Null values in the `mask` leave the row unchanged.
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+    /// let mask = BooleanChunked::new("mask".into(), [false, true, false]);
+    /// let out = df.with_null_mask(&mask)?;
+    /// assert_eq!(out.column("b")?.null_count(), 1);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    #[cfg(feature = "zip_with")]
+    pub fn with_null_mask(&self, mask: &BooleanChunked) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            mask.len() == self.height(),
+            ShapeMismatch: "null mask has length {} but the DataFrame has height {}",
+            mask.len(), self.height()
+        );
+        let keep = !&mask.fill_null_with_values(false)?;
+        let columns = self.try_apply_columns_par(&|c| {
+            let nulls = Column::full_null(c.name().clone(), 1, c.dtype());
+            c.zip_with_same_type(&keep, &nulls)
+        })?;
+        Ok(unsafe { DataFrame::new_no_checks(self.height(), columns) })
+    }
+
     /// Slice the [`DataFrame`] along the rows.
     ///
     /// # Example
@@ -3091,6 +3274,82 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(height, columns) })
     }
 
+    /// Remove duplicate rows, using the precomputed `UInt64` hashes in `hash_col` as the key.
+    ///
+    /// The rows are not hashed again. Rows with the same hash are compared on all other columns,
+    /// so hash collisions don't merge distinct rows. The order of the rows is maintained; with
+    /// [`UniqueKeepStrategy::Last`] the rows are ordered by their last occurrence.
+    pub fn unique_by_hash_column(
+        &self,
+        hash_col: &str,
+        keep: UniqueKeepStrategy,
+    ) -> PolarsResult<DataFrame> {
+        let hashes = self.column(hash_col)?;
+        polars_ensure!(
+            hashes.dtype() == &DataType::UInt64,
+            SchemaMismatch: "hash column '{}' must be of dtype UInt64, got {}", hash_col, hashes.dtype()
+        );
+        polars_ensure!(
+            !hashes.has_nulls(),
+            ComputeError: "hash column '{}' must not contain nulls", hash_col
+        );
+        let hashes = hashes.as_materialized_series().rechunk();
+        let hashes = hashes.u64()?.cont_slice()?;
+
+        let others = self
+            .columns
+            .iter()
+            .filter(|c| c.name().as_str() != hash_col)
+            .map(|c| c.as_materialized_series().rechunk())
+            .collect::<Vec<_>>();
+        let others = others
+            .iter()
+            .map(|s| s.into_total_eq_inner())
+            .collect::<Vec<_>>();
+        // SAFETY: the indices are in bounds of all columns.
+        let rows_equal = |a: usize, b: usize| {
+            others
+                .iter()
+                .all(|c| unsafe { c.eq_element_unchecked(a, b) })
+        };
+
+        // For every distinct row: the first and last occurrence and the number of occurrences.
+        let mut distinct: Vec<(IdxSize, IdxSize, IdxSize)> = Vec::new();
+        // Hash to the distinct rows with that hash.
+        let mut buckets: PlIdHashMap<u64, polars_utils::idx_vec::UnitVec<usize>> =
+            PlIdHashMap::default();
+        for (i, &h) in hashes.iter().enumerate() {
+            let bucket = buckets.entry(h).or_default();
+            match bucket
+                .iter()
+                .find(|&&d| rows_equal(distinct[d].0 as usize, i))
+            {
+                Some(&d) => {
+                    distinct[d].1 = i as IdxSize;
+                    distinct[d].2 += 1;
+                },
+                None => {
+                    bucket.push(distinct.len());
+                    distinct.push((i as IdxSize, i as IdxSize, 1));
+                },
+            }
+        }
+
+        let idx: Vec<IdxSize> = match keep {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Any => {
+                distinct.iter().map(|d| d.0).collect()
+            },
+            UniqueKeepStrategy::Last => {
+                let mut idx = distinct.iter().map(|d| d.1).collect::<Vec<_>>();
+                idx.sort_unstable();
+                idx
+            },
+            UniqueKeepStrategy::None => distinct.iter().filter(|d| d.2 == 1).map(|d| d.0).collect(),
+        };
+        // SAFETY: the indices are in bounds.
+        Ok(unsafe { self._take_unchecked_slice(&idx, true) })
+    }
+
     /// Get a mask of all the unique rows in the [`DataFrame`].
     ///
     /// # Example
@@ -3358,6 +3617,74 @@ impl DataFrame {
         DataFrame::new(new_cols)
     }
 
+    /// Recursively unnest all `Struct` columns until no `Struct` columns remain or `max_depth`
+    /// levels have been unnested.
+    ///
+    /// Fields whose name is already taken by another column get the unnesting depth appended as
+    /// `"{name}_{depth}"`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_recursive(&self, max_depth: Option<usize>) -> PolarsResult<DataFrame> {
+        let mut df = self.clone();
+        let mut depth = 0;
+        while max_depth.is_none_or(|max_depth| depth < max_depth)
+            && df.columns.iter().any(|c| c.dtype().is_struct())
+        {
+            depth += 1;
+
+            let mut names = df
+                .columns
+                .iter()
+                .filter(|c| !c.dtype().is_struct())
+                .map(|c| c.name().clone())
+                .collect::<PlHashSet<_>>();
+            let mut new_cols = Vec::with_capacity(df.width());
+            for c in &df.columns {
+                if !c.dtype().is_struct() {
+                    new_cols.push(c.clone());
+                    continue;
+                }
+
+                for mut field in c.struct_()?.fields_as_series() {
+                    if names.contains(field.name()) {
+                        let name = format_pl_smallstr!("{}_{}", field.name(), depth);
+                        field.rename(name);
+                    }
+                    names.insert(field.name().clone());
+                    new_cols.push(Column::from(field));
+                }
+            }
+            df = DataFrame::new(new_cols)?;
+        }
+        Ok(df)
+    }
+
+    /// Unnest all top-level `Struct` columns in a single pass.
+    ///
+    /// The fields are named `"{column}{name_separator}{field}"`, so fields of different `Struct`
+    /// columns don't collide. Nested `Struct` fields are not unnested.
+    #[cfg(feature = "dtype-struct")]
+    pub fn explode_structs(&mut self, name_separator: &str) -> PolarsResult<()> {
+        if !self.columns.iter().any(|c| c.dtype().is_struct()) {
+            return Ok(());
+        }
+
+        let mut new_cols = Vec::with_capacity(self.width());
+        for c in &self.columns {
+            if !c.dtype().is_struct() {
+                new_cols.push(c.clone());
+                continue;
+            }
+
+            for mut field in c.struct_()?.fields_as_series() {
+                let name = format_pl_smallstr!("{}{name_separator}{}", c.name(), field.name());
+                field.rename(name);
+                new_cols.push(Column::from(field));
+            }
+        }
+        *self = DataFrame::new(new_cols)?;
+        Ok(())
+    }
+
     pub(crate) fn infer_height(cols: &[Column]) -> usize {
         cols.first().map_or(0, Column::len)
     }
@@ -3696,4 +4023,217 @@ mod test {
         df.apply("x", |f| f.cast(&DataType::Int8).unwrap()).unwrap();
         assert_ne!(&schema_before, df.schema());
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_unnest_recursive() -> PolarsResult<()> {
+        let inner = StructChunked::from_series(
+            "b".into(),
+            2,
+            [
+                Series::new("a".into(), [1, 2]),
+                Series::new("c".into(), [3, 4]),
+            ]
+            .iter(),
+        )?
+        .into_series();
+        let outer = StructChunked::from_series(
+            "s".into(),
+            2,
+            [Series::new("a".into(), [5, 6]), inner].iter(),
+        )?
+        .into_column();
+        let df = DataFrame::new(vec![outer])?;
+
+        let out = df.unnest_recursive(Some(1))?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert!(out.column("b")?.dtype().is_struct());
+
+        let out = df.unnest_recursive(None)?;
+        assert_eq!(out.get_column_names(), &["a", "a_2", "c"]);
+        assert_eq!(
+            out.column("a_2")?.as_materialized_series(),
+            &Series::new("a_2".into(), [1, 2])
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_explode_structs() -> PolarsResult<()> {
+        let s1 = StructChunked::from_series(
+            "s1".into(),
+            2,
+            [
+                Series::new("a".into(), [1, 2]),
+                Series::new("b".into(), [3, 4]),
+            ]
+            .iter(),
+        )?
+        .into_column();
+        let s2 =
+            StructChunked::from_series("s2".into(), 2, [Series::new("a".into(), [5, 6])].iter())?
+                .into_column();
+        let mut df = DataFrame::new(vec![s1, Column::new("x".into(), [7, 8]), s2])?;
+
+        df.explode_structs(".")?;
+        assert_eq!(df.get_column_names(), &["s1.a", "s1.b", "x", "s2.a"]);
+        assert_eq!(
+            df.column("s2.a")?.as_materialized_series(),
+            &Series::new("s2.a".into(), [5, 6])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_ordered() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1, 2],
+            "b" => ["x", "y"],
+            "c" => [1.0, 2.0],
+        }?;
+        let schema = Schema::from_iter([
+            Field::new("c".into(), DataType::Float64),
+            Field::new("a".into(), DataType::Int32),
+        ]);
+
+        let out = df.select_ordered(&schema)?;
+        assert_eq!(out.get_column_names(), &["c", "a"]);
+
+        let schema = Schema::from_iter([Field::new("d".into(), DataType::Int32)]);
+        assert!(df.select_ordered(&schema).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_by_predicate() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1, 2, 3, 4],
+            "b" => ["w", "x", "y", "z"],
+        }?;
+        let mask = BooleanChunked::new("".into(), [Some(false), Some(true), None, Some(true)]);
+
+        let rows = df.get_by_predicate(&mask, None)?;
+        assert_eq!(
+            rows,
+            &[
+                vec![AnyValue::Int32(2), AnyValue::String("x")],
+                vec![AnyValue::Int32(4), AnyValue::String("z")],
+            ]
+        );
+
+        let rows = df.get_by_predicate(&mask, Some(1))?;
+        assert_eq!(rows.len(), 1);
+
+        let mask = BooleanChunked::new("".into(), [true]);
+        assert!(df.get_by_predicate(&mask, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_columns_typed() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [1, 2, 3],
+            "b" => [4, 5, 6],
+            "c" => ["x", "y", "z"],
+        }?;
+
+        df.map_columns_typed(|c| Ok(c.reverse()))?;
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(3), Some(2), Some(1)]
+        );
+
+        let err = df
+            .map_columns_typed(|c| match c.dtype() {
+                DataType::Int32 => c.cast(&DataType::Int64),
+                _ => Ok(c.slice(0, 1)),
+            })
+            .unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("a, b, c"));
+        assert_eq!(df.column("a")?.dtype(), &DataType::Int32);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "zip_with")]
+    fn test_with_null_mask() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1), Some(2), None, Some(4)],
+            "b" => ["w", "x", "y", "z"]
+        }?;
+        let mask = BooleanChunked::new("".into(), [Some(true), None, Some(false), Some(true)]);
+
+        let out = df.with_null_mask(&mask)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[None, Some(2), None, None]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.str()?),
+            &[None, Some("x"), Some("y"), None]
+        );
+        assert_eq!(out.get_column_names(), df.get_column_names());
+
+        let short = BooleanChunked::new("".into(), [true]);
+        assert!(df.with_null_mask(&short).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_join_in_place() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [Some(1), None, Some(3), Some(1), Some(5)],
+            "b" => ["v", "w", "x", "y", "z"]
+        }?;
+        let right = Series::new("r".into(), [Some(1i64), None, Some(5), Some(7)]);
+
+        df.semi_join_in_place(&right, "a")?;
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(1), Some(1), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(df.column("b")?.str()?),
+            &[Some("v"), Some("y"), Some("z")]
+        );
+
+        assert!(df.semi_join_in_place(&right, "missing").is_err());
+
+        // The keys are compared as their supertype, so `3.5` must not be truncated to `3`.
+        let mut df = df!("a" => [1, 2, 3, 4])?;
+        let right = Series::new("r".into(), [2.0, 3.5, 4.0]);
+        df.semi_join_in_place(&right, "a")?;
+        assert_eq!(Vec::from(df.column("a")?.i32()?), &[Some(2), Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_by_hash_column() -> PolarsResult<()> {
+        // Rows 0 and 3 share a hash but differ, i.e. a collision.
+        let df = df! {
+            "h" => [1u64, 2, 1, 1, 2],
+            "a" => ["x", "y", "x", "z", "y"],
+        }?;
+
+        let out = df.unique_by_hash_column("h", UniqueKeepStrategy::First)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.str()?),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+        let out = df.unique_by_hash_column("h", UniqueKeepStrategy::Last)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.str()?),
+            &[Some("x"), Some("z"), Some("y")]
+        );
+        let out = df.unique_by_hash_column("h", UniqueKeepStrategy::None)?;
+        assert_eq!(Vec::from(out.column("a")?.str()?), &[Some("z")]);
+
+        assert!(
+            df.unique_by_hash_column("a", UniqueKeepStrategy::First)
+                .is_err()
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
-use polars_error::{PolarsResult, polars_bail};
-use polars_utils::aliases::{InitHashMaps, PlHashSet};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::aliases::{InitHashMaps, PlHashMap, PlHashSet};
 
 use super::DataFrame;
 use super::column::Column;
@@ -64,4 +64,48 @@ impl DataFrame {
 
         Ok(())
     }
+
+    /// Ensure the column names of this [`DataFrame`] are unique.
+    ///
+    /// Unlike the check in [`DataFrame::new`], which stops at the first duplicate, the error lists
+    /// every name that occurs more than once.
+    pub fn validate_column_name_uniqueness(&self) -> PolarsResult<()> {
+        // The schema can't be used here, as it would already have collapsed the duplicates.
+        let mut counts = PlHashMap::with_capacity(self.width());
+        let mut duplicates = Vec::new();
+        for name in self.get_column_names() {
+            let count = counts.entry(name).or_insert(0usize);
+            *count += 1;
+            if *count == 2 {
+                duplicates.push(name.as_str());
+            }
+        }
+
+        polars_ensure!(
+            duplicates.is_empty(),
+            SchemaMismatch: "duplicate column names found: {:?}", duplicates
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_validate_column_name_uniqueness() -> PolarsResult<()> {
+        let df = df!["a" => [1], "b" => [2], "c" => [3]]?;
+        df.validate_column_name_uniqueness()?;
+
+        let columns = ["a", "b", "a", "c", "b", "a"]
+            .into_iter()
+            .map(|name| Column::new(name.into(), [1]))
+            .collect();
+        let df = unsafe { DataFrame::_new_no_checks_impl(1, columns) };
+        let err = df.validate_column_name_uniqueness().unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+        assert!(err.to_string().contains(r#"["a", "b"]"#));
+        Ok(())
+    }
 }
@@ -56,7 +56,7 @@ pub use crate::schema::*;
 pub use crate::series::arithmetic::checked::NumOpsDispatchChecked;
 pub use crate::series::arithmetic::{LhsNumOps, NumOpsDispatch};
 pub use crate::series::implementations::null::NullChunked;
-pub use crate::series::{IntoSeries, Series, SeriesTrait};
+pub use crate::series::{IntoSeries, OwnedArrayToSeries, Series, SeriesTrait};
 pub(crate) use crate::utils::CustomIterTools;
 pub use crate::utils::IntoVec;
 pub use crate::{datatypes, df, with_match_categorical_physical_type};
@@ -1,3 +1,4 @@
+use arrow::array::OwnedArray;
 #[cfg(feature = "dtype-categorical")]
 use arrow::compute::concatenate::concatenate_unchecked;
 use arrow::datatypes::Metadata;
@@ -817,6 +818,33 @@ impl TryFrom<(&ArrowField, ArrayRef)> for Series {
     }
 }
 
+impl TryFrom<OwnedArray> for Series {
+    type Error = PolarsError;
+
+    fn try_from(array: OwnedArray) -> PolarsResult<Self> {
+        Series::try_from(array.into_inner())
+    }
+}
+
+/// Conversion of an [`OwnedArray`] into a [`Series`].
+pub trait OwnedArrayToSeries {
+    /// Convert to a [`Series`].
+    ///
+    /// If `schema` has a field with the name of the array, its metadata is used to restore the
+    /// Polars data type, e.g. for categoricals.
+    fn to_series(&self, schema: &ArrowSchema) -> PolarsResult<Series>;
+}
+
+impl OwnedArrayToSeries for OwnedArray {
+    fn to_series(&self, schema: &ArrowSchema) -> PolarsResult<Series> {
+        let array = self.array().to_boxed();
+        match schema.get(self.name()) {
+            Some(field) => Series::try_from((field, array)),
+            None => Series::try_from((self.name().clone(), array)),
+        }
+    }
+}
+
 /// Used to convert a [`ChunkedArray`], `&dyn SeriesTrait` and [`Series`]
 /// into a [`Series`].
 /// # Safety
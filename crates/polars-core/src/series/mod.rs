@@ -226,6 +226,22 @@ impl Series {
         chunks
     }
 
+    /// Get references to the underlying arrow arrays, one per chunk, without copying.
+    ///
+    /// These are the arrays of the physical representation, e.g. `Int32` for `Date`. Use
+    /// [`Series::to_arrow`] to convert a chunk to its logical arrow type.
+    pub fn to_arrow_chunks(&self) -> Vec<&dyn Array> {
+        self.chunks().iter().map(|arr| arr.as_ref()).collect()
+    }
+
+    /// Convert into the underlying arrow arrays, one per chunk.
+    ///
+    /// The arrays are moved out if this [`Series`] is the sole owner of its data and cloned
+    /// otherwise. Like [`Series::to_arrow_chunks`] these are the physical arrays.
+    pub fn into_arrow_chunks(self) -> Vec<Box<dyn Array>> {
+        self.into_chunks()
+    }
+
     // TODO! this probably can now be removed, now we don't have special case for structs.
     pub fn select_chunk(&self, i: usize) -> Self {
         let mut new = self.clear();
@@ -754,6 +770,98 @@ impl Series {
         Ok(unsafe { self.take_unchecked(&idx) })
     }
 
+    /// Broadcast this [`Series`] to `length`.
+    ///
+    /// A unit-length [`Series`] is repeated `length` times. A [`Series`] that already has the
+    /// target length is returned as is. Any other length is an error.
+    pub fn broadcast_to(&self, length: usize) -> PolarsResult<Series> {
+        if self.len() == length {
+            return Ok(self.clone());
+        }
+        polars_ensure!(
+            self.len() == 1,
+            ShapeMismatch: "cannot broadcast Series '{}' of length {} to length {}",
+            self.name(), self.len(), length
+        );
+        Ok(self.new_from_index(0, length))
+    }
+
+    /// Create a [`DataFrame`] with a column `self.shift(lag)` for every lag in `lags`.
+    ///
+    /// The columns are named `{name}{suffix}{lag}`. This is only supported for numeric dtypes.
+    pub fn lag_matrix(&self, lags: &[i64], suffix: &str) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            self.dtype().is_numeric(),
+            InvalidOperation: "'lag_matrix' operation not supported for dtype `{}`", self.dtype()
+        );
+        let columns = lags
+            .iter()
+            .map(|&lag| {
+                let name = polars_utils::format_pl_smallstr!("{}{suffix}{lag}", self.name());
+                self.shift(lag).with_name(name).into_column()
+            })
+            .collect::<Vec<_>>();
+        DataFrame::empty_with_height(self.len()).hstack(&columns)
+    }
+
+    /// Get the median absolute deviation `median(|x - median(x)|)`, ignoring nulls.
+    ///
+    /// With `ddof == 1` the result is scaled by `1.4826`, which makes it a consistent estimator
+    /// of the standard deviation for normally distributed data. Returns `None` if there are no
+    /// non-null values.
+    pub fn mad(&self, ddof: u8) -> PolarsResult<Option<f64>> {
+        polars_ensure!(
+            self.dtype().is_primitive_numeric(),
+            InvalidOperation: "'mad' operation not supported for dtype `{}`", self.dtype()
+        );
+        polars_ensure!(
+            ddof <= 1,
+            InvalidOperation: "'mad' only supports a `ddof` of 0 or 1, got {}", ddof
+        );
+
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+        let Some(median) = ca.median() else {
+            return Ok(None);
+        };
+        let deviations = ca.apply_values(|v| (v - median).abs());
+        let scale = if ddof == 1 { 1.4826 } else { 1.0 };
+        Ok(deviations.median().map(|mad| mad * scale))
+    }
+
+    /// Sort the values at the positions where `mask` is `true`, leaving all other values in place.
+    ///
+    /// The sorted values are written back to the masked positions in order. Null values in the
+    /// `mask` count as `false`. This runs in `O(n + k log k)` where `k` is the number of masked
+    /// values.
+    pub fn conditional_sort(
+        &self,
+        mask: &BooleanChunked,
+        options: SortOptions,
+    ) -> PolarsResult<Series> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "mask has length {} but the Series has length {}",
+            mask.len(), self.len()
+        );
+
+        let positions = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| (m == Some(true)).then_some(i as IdxSize))
+            .collect::<Vec<_>>();
+        // SAFETY: the positions are in bounds.
+        let masked = unsafe { self.take_slice_unchecked(&positions) };
+        let order = masked.arg_sort(options);
+
+        let mut idx = (0..self.len() as IdxSize).collect::<Vec<_>>();
+        for (&dst, src) in positions.iter().zip(order.into_no_null_iter()) {
+            idx[dst as usize] = positions[src as usize];
+        }
+        // SAFETY: the indices are in bounds.
+        Ok(unsafe { self.take_slice_unchecked(&idx) })
+    }
+
     #[cfg(feature = "dot_product")]
     pub fn dot(&self, other: &Series) -> PolarsResult<f64> {
         std::ops::Mul::mul(self, other)?.sum::<f64>()
@@ -1190,4 +1298,109 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    fn lag_matrix() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1i32, 2, 3]);
+        let df = s.lag_matrix(&[1, -1], "_lag_")?;
+        assert_eq!(df.get_column_names(), &["a_lag_1", "a_lag_-1"]);
+        assert_eq!(
+            Vec::from(df.column("a_lag_1")?.i32()?),
+            &[None, Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(df.column("a_lag_-1")?.i32()?),
+            &[Some(2), Some(3), None]
+        );
+
+        let s = Series::new("a".into(), &["x"]);
+        assert!(s.lag_matrix(&[1], "_").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn arrow_chunks() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), &[1i32, 2]);
+        s.append(&Series::new("a".into(), &[3i32]))?;
+
+        let chunks = s.to_arrow_chunks();
+        assert_eq!(chunks.len(), 2);
+        assert!(std::ptr::addr_eq(chunks[0], s.chunks()[0].as_ref()));
+
+        let chunks = s.into_arrow_chunks();
+        assert_eq!(
+            chunks.iter().map(|arr| arr.len()).collect::<Vec<_>>(),
+            &[2, 1]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn mad() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(1i32), Some(2), None, Some(3), Some(4), Some(100)],
+        );
+        assert_eq!(s.mad(0)?, Some(1.0));
+        assert_eq!(s.mad(1)?, Some(1.4826));
+
+        let s = Series::new_null("a".into(), 3).cast(&DataType::Float64)?;
+        assert_eq!(s.mad(0)?, None);
+        assert!(s.mad(2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn conditional_sort() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(5), Some(4), None, Some(1), Some(3), Some(0)],
+        );
+        let mask = BooleanChunked::new(
+            "".into(),
+            [
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                None,
+                Some(true),
+            ],
+        );
+
+        let out = s.conditional_sort(&mask, SortOptions::default())?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[None, Some(4), Some(0), Some(1), Some(3), Some(5)]
+        );
+
+        let out = s.conditional_sort(
+            &mask,
+            SortOptions::default()
+                .with_order_descending(true)
+                .with_nulls_last(true),
+        )?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(5), Some(4), Some(1), Some(0), Some(3), None]
+        );
+
+        assert!(
+            s.conditional_sort(&mask.slice(0, 2), SortOptions::default())
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_to() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1i32]);
+        let out = s.broadcast_to(3)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(1), Some(1)]);
+
+        let s = Series::new("a".into(), &[1i32, 2]);
+        assert!(s.broadcast_to(2)?.equals(&s));
+        assert!(s.broadcast_to(3).is_err());
+        Ok(())
+    }
 }
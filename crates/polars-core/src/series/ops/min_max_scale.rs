@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Rescale the values linearly so that the minimum maps to `new_min` and the maximum to
+    /// `new_max`, i.e. `(x - min) / (max - min) * (new_max - new_min) + new_min`.
+    ///
+    /// The result is always `Float64`. Null values stay null, and if all values are equal they
+    /// are mapped to `new_min`.
+    pub fn min_max_scale(&self, new_min: f64, new_max: f64) -> PolarsResult<Series> {
+        polars_ensure!(
+            self.dtype().is_primitive_numeric(),
+            InvalidOperation: "`min_max_scale` operation not supported for dtype `{}`",
+            self.dtype()
+        );
+
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64().unwrap();
+        let min = s.min_reduce()?.value().extract::<f64>();
+        let max = s.max_reduce()?.value().extract::<f64>();
+        let (Some(min), Some(max)) = (min, max) else {
+            return Ok(Series::full_null(
+                self.name().clone(),
+                self.len(),
+                &DataType::Float64,
+            ));
+        };
+
+        let out = if max == min {
+            ca.apply_values(|_| new_min)
+        } else {
+            let scale = (new_max - new_min) / (max - min);
+            ca.apply_values(|x| (x - min) * scale + new_min)
+        };
+        Ok(out.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_max_scale() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(2), None, Some(4), Some(6)]);
+        let out = s.min_max_scale(0.0, 1.0)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), None, Some(0.5), Some(1.0)]
+        );
+
+        let out = s.min_max_scale(-1.0, 1.0)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(-1.0), None, Some(0.0), Some(1.0)]
+        );
+
+        let s = Series::new("a".into(), [Some(3.0), None, Some(3.0)]);
+        let out = s.min_max_scale(5.0, 10.0)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(5.0), None, Some(5.0)]);
+
+        let s = Series::full_null("a".into(), 2, &DataType::Int32);
+        let out = s.min_max_scale(0.0, 1.0)?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.null_count(), 2);
+
+        assert!(
+            Series::new("a".into(), ["x"])
+                .min_max_scale(0.0, 1.0)
+                .is_err()
+        );
+        Ok(())
+    }
+}
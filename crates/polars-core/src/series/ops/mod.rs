@@ -1,9 +1,13 @@
 mod downcast;
 mod extend;
+mod min_max_scale;
 mod null;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 mod reshape;
+#[cfg(feature = "rolling_window")]
+mod sliding_dot;
+mod top_k_stable;
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
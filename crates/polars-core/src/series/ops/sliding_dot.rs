@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+#[inline]
+fn dot(values: &[f64], kernel: &[f64]) -> f64 {
+    values.iter().zip(kernel).map(|(v, k)| v * k).sum()
+}
+
+impl Series {
+    /// Compute the dot product of every window with a fixed `kernel`.
+    ///
+    /// The window size is given by the length of `kernel`, so `options.window_size` and
+    /// `options.weights` are not used. For windows that are cut off at the edges, the kernel is
+    /// aligned with the rows that are in the window. Null values are skipped, windows with less
+    /// than `options.min_periods` valid values produce a null.
+    pub fn sliding_dot_product(
+        &self,
+        kernel: &[f64],
+        options: RollingOptionsFixedWindow,
+    ) -> PolarsResult<Series> {
+        polars_ensure!(
+            !kernel.is_empty(),
+            ComputeError: "`kernel` of `sliding_dot_product` must not be empty"
+        );
+        polars_ensure!(
+            options.min_periods <= kernel.len(),
+            ComputeError: "`kernel` length: {} should be >= `min_periods`: {}",
+            kernel.len(), options.min_periods
+        );
+        polars_ensure!(
+            self.dtype().is_primitive_numeric(),
+            InvalidOperation: "`sliding_dot_product` operation not supported for dtype `{}`",
+            self.dtype()
+        );
+
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64().unwrap().rechunk();
+        let arr = ca.downcast_as_array();
+        let values = arr.values().as_slice();
+        let validity = arr.validity();
+
+        let len = values.len();
+        let window_size = kernel.len();
+        let left_size = if options.center {
+            window_size - window_size.div_ceil(2)
+        } else {
+            window_size - 1
+        };
+
+        let iter = (0..len).map(|idx| {
+            // The first row of the window if it wasn't cut off at the start.
+            let window_start = idx as isize - left_size as isize;
+            let start = window_start.max(0) as usize;
+            let end = len.min((window_start + window_size as isize) as usize);
+            if end - start < options.min_periods {
+                return None;
+            }
+
+            let kernel_offset = (start as isize - window_start) as usize;
+            let kernel = &kernel[kernel_offset..kernel_offset + (end - start)];
+            let window = &values[start..end];
+            match validity {
+                Some(validity) if validity.null_count_range(start, end - start) > 0 => {
+                    let mut valid = 0;
+                    let mut acc = 0.0;
+                    for (i, (v, k)) in window.iter().zip(kernel).enumerate() {
+                        // SAFETY: `start + i` is within the bounds of the array.
+                        if unsafe { validity.get_bit_unchecked(start + i) } {
+                            valid += 1;
+                            acc += v * k;
+                        }
+                    }
+                    (valid >= options.min_periods).then_some(acc)
+                },
+                _ => Some(dot(window, kernel)),
+            }
+        });
+
+        Ok(Float64Chunked::from_iter_options(self.name().clone(), iter).into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sliding_dot_product() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1), Some(2), None, Some(4), Some(5)]);
+        let options = RollingOptionsFixedWindow {
+            min_periods: 2,
+            ..Default::default()
+        };
+
+        let out = s.sliding_dot_product(&[1.0, 10.0], options.clone())?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, Some(21.0), None, None, Some(54.0)]
+        );
+
+        let options = RollingOptionsFixedWindow {
+            min_periods: 1,
+            ..options
+        };
+        let out = s.sliding_dot_product(&[1.0, 10.0], options)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(10.0), Some(21.0), Some(2.0), Some(40.0), Some(54.0)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use polars_utils::total_ord::{TotalEq, TotalOrd};
+
+use crate::downcast_as_macro_arg_physical;
+use crate::prelude::*;
+
+/// Get the indices of the `k` largest non-null values of `ca` in ascending order.
+fn top_k_stable_idx<T: PolarsNumericType>(ca: &ChunkedArray<T>, k: usize) -> IdxCa {
+    let mut values = ca.iter().flatten().collect::<Vec<_>>();
+    let k = k.min(values.len());
+    if k == 0 {
+        return IdxCa::from_vec(ca.name().clone(), vec![]);
+    }
+
+    // Find the k-th largest value in O(n).
+    let (_, threshold, _) = values.select_nth_unstable_by(k - 1, |a, b| b.tot_cmp(a));
+    let threshold = *threshold;
+    let n_greater = values.iter().filter(|v| v.tot_gt(&threshold)).count();
+
+    // Values equal to the threshold are taken in first-occurrence order.
+    let mut n_ties = k - n_greater;
+    let idx = ca
+        .iter()
+        .enumerate()
+        .filter_map(|(i, opt_v)| {
+            let v = opt_v?;
+            let take = if v.tot_gt(&threshold) {
+                true
+            } else if n_ties > 0 && v.tot_eq(&threshold) {
+                n_ties -= 1;
+                true
+            } else {
+                false
+            };
+            take.then_some(i as IdxSize)
+        })
+        .collect();
+    IdxCa::from_vec(ca.name().clone(), idx)
+}
+
+impl Series {
+    /// Get the `k` largest values, in the order in which they appear in this [`Series`].
+    ///
+    /// Null values are never selected, so the output is shorter than `k` if there are less than
+    /// `k` non-null values. If there are ties at the smallest selected value, the first
+    /// occurrences are taken.
+    pub fn top_k_stable(&self, k: usize) -> PolarsResult<Series> {
+        let s = self.to_physical_repr();
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            InvalidOperation: "`top_k_stable` operation not supported for dtype `{}`",
+            self.dtype()
+        );
+
+        macro_rules! dispatch {
+            ($ca:expr) => {{ top_k_stable_idx($ca, k) }};
+        }
+        let idx = downcast_as_macro_arg_physical!(s, dispatch);
+        // SAFETY: the indices are in bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_top_k_stable() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(3), None, Some(5), Some(1), Some(5), Some(3)],
+        );
+
+        let out = s.top_k_stable(3)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(3), Some(5), Some(5)]);
+
+        let out = s.top_k_stable(2)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(5), Some(5)]);
+
+        let out = s.top_k_stable(10)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), Some(5), Some(1), Some(5), Some(3)]
+        );
+        Ok(())
+    }
+}
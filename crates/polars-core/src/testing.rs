@@ -61,6 +61,45 @@ impl DataFrame {
         Ok(())
     }
 
+    /// Assert that the schema of the [`DataFrame`] equals `expected`.
+    ///
+    /// # Panics
+    /// Panics if the schemas differ, with a message listing the missing (`-`), unexpected (`+`)
+    /// and mismatched (`~`) columns as `{name}: {dtype}`.
+    #[cfg(any(test, feature = "test-utils"))]
+    #[track_caller]
+    pub fn assert_schema_eq(&self, expected: &Schema) {
+        let schema = self.schema();
+        if schema.as_ref() == expected {
+            return;
+        }
+
+        let mut msg = format!(
+            "schema mismatch: expected {} columns, got {}\n",
+            expected.len(),
+            schema.len()
+        );
+        for (name, dtype) in expected.iter() {
+            match schema.get(name) {
+                None => msg.push_str(&format!("- {name}: {dtype}\n")),
+                Some(got) if got != dtype => {
+                    msg.push_str(&format!("~ {name}: {dtype} (got {got})\n"))
+                },
+                Some(_) => {},
+            }
+        }
+        for (name, dtype) in schema.iter() {
+            if !expected.contains(name) {
+                msg.push_str(&format!("+ {name}: {dtype}\n"));
+            }
+        }
+        if schema.len() == expected.len() && expected.iter().all(|(n, d)| schema.get(n) == Some(d))
+        {
+            msg.push_str("column order differs\n");
+        }
+        panic!("{msg}")
+    }
+
     /// Check if [`DataFrame`]s are equal. Note that `None == None` evaluates to `false`
     ///
     /// # Example
@@ -143,6 +182,31 @@ macro_rules! assert_df_eq {
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_assert_schema_eq() {
+        let df = df!(
+            "a" => [1, 2],
+            "b" => ["x", "y"]
+        )
+        .unwrap();
+
+        df.assert_schema_eq(&Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("b".into(), DataType::String),
+        ]));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            df.assert_schema_eq(&Schema::from_iter([
+                Field::new("a".into(), DataType::Int64),
+                Field::new("c".into(), DataType::String),
+            ]))
+        }));
+        let msg = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(msg.contains("~ a: i64 (got i32)"));
+        assert!(msg.contains("- c: str"));
+        assert!(msg.contains("+ b: str"));
+    }
+
     #[test]
     fn test_series_equals() {
         let a = Series::new("a".into(), &[1_u32, 2, 3]);
@@ -10,6 +10,8 @@ mod filter;
 mod gather;
 mod group_iter;
 mod literal;
+#[cfg(feature = "rank")]
+mod ntile;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
 mod slice;
@@ -34,6 +36,8 @@ pub(crate) use eval::*;
 pub(crate) use filter::*;
 pub(crate) use gather::*;
 pub(crate) use literal::*;
+#[cfg(feature = "rank")]
+pub(crate) use ntile::*;
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::prelude::*;
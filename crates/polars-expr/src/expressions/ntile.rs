@@ -0,0 +1,59 @@
+use polars_core::prelude::*;
+use polars_ops::series::SeriesRank;
+
+use super::*;
+use crate::expressions::{AggregationContext, PhysicalExpr};
+
+/// `NTILE(n)`: buckets the values by their rank, `floor((rank - 1) * n / count) + 1`.
+///
+/// In a window with an `order_by`, the groups are already sorted, so ties are ranked in the
+/// `order_by` order within every partition.
+pub struct NtileExpr {
+    input: Arc<dyn PhysicalExpr>,
+    n: u32,
+    expr: Expr,
+}
+
+impl NtileExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, n: u32, expr: Expr) -> Self {
+        Self { input, n, expr }
+    }
+}
+
+impl PhysicalExpr for NtileExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        let c = self.input.evaluate(df, state)?;
+        Ok(c.as_materialized_series().ntile(self.n)?.into_column())
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut ac = self.input.evaluate_on_groups(df, groups, state)?;
+        let agg = ac.aggregated();
+        let ca = agg
+            .list()?
+            .try_apply_amortized(|s| s.as_ref().ntile(self.n).map(|ca| ca.into_series()))?;
+
+        ac.with_update_groups(UpdateGroups::WithSeriesLen);
+        ac.with_values(ca.into_column(), true, Some(&self.expr))?;
+        Ok(ac)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        let field = self.input.to_field(input_schema)?;
+        Ok(Field::new(field.name().clone(), IDX_DTYPE))
+    }
+
+    fn is_scalar(&self) -> bool {
+        false
+    }
+}
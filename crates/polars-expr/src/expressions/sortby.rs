@@ -53,19 +53,42 @@ fn check_groups(a: &GroupsType, b: &GroupsType) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Sort the indices within every group by the `sort_by_s` keys, in order of precedence.
 pub(super) fn update_groups_sort_by(
     groups: &GroupsType,
-    sort_by_s: &Series,
-    options: &SortOptions,
+    sort_by_s: &[Series],
+    options: &[SortOptions],
 ) -> PolarsResult<GroupsType> {
+    debug_assert_eq!(sort_by_s.len(), options.len());
     // Will trigger a gather for every group, so rechunk before.
-    let sort_by_s = sort_by_s.rechunk();
-    let groups = POOL.install(|| {
-        groups
-            .par_iter()
-            .map(|indicator| sort_by_groups_single_by(indicator, &sort_by_s, options))
-            .collect::<PolarsResult<_>>()
-    })?;
+    let sort_by_s = sort_by_s.iter().map(|s| s.rechunk()).collect::<Vec<_>>();
+    let groups = if let [sort_by_s] = sort_by_s.as_slice() {
+        POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|indicator| sort_by_groups_single_by(indicator, sort_by_s, &options[0]))
+                .collect::<PolarsResult<_>>()
+        })?
+    } else {
+        let descending = options.iter().map(|o| o.descending).collect::<Vec<_>>();
+        let nulls_last = options.iter().map(|o| o.nulls_last).collect::<Vec<_>>();
+        POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|indicator| {
+                    // We are already in par iter.
+                    sort_by_groups_multiple_by(
+                        indicator,
+                        &sort_by_s,
+                        &descending,
+                        &nulls_last,
+                        false,
+                        false,
+                    )
+                })
+                .collect::<PolarsResult<_>>()
+        })?
+    };
 
     Ok(GroupsType::Idx(groups))
 }
@@ -356,12 +379,12 @@ impl PhysicalExpr for SortByExpr {
                 || {
                     update_groups_sort_by(
                         groups,
-                        &sort_by_s,
-                        &SortOptions {
+                        std::slice::from_ref(&sort_by_s),
+                        &[SortOptions {
                             descending: descending[0],
                             nulls_last: nulls_last[0],
                             ..Default::default()
-                        },
+                        }],
                     )
                 },
             );
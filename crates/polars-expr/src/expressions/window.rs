@@ -20,7 +20,8 @@ pub struct WindowExpr {
     /// the root column that the Function will be applied on.
     /// This will be used to create a smaller DataFrame to prevent taking unneeded columns by index
     pub(crate) group_by: Vec<Arc<dyn PhysicalExpr>>,
-    pub(crate) order_by: Option<(Arc<dyn PhysicalExpr>, SortOptions)>,
+    /// The keys the groups are sorted by, in order of precedence.
+    pub(crate) order_by: Option<Vec<(Arc<dyn PhysicalExpr>, SortOptions)>>,
     pub(crate) apply_columns: Vec<PlSmallStr>,
     /// A function Expr. i.e. Mean, Median, Max, etc.
     pub(crate) function: Expr,
@@ -331,68 +332,9 @@ impl WindowExpr {
             (_, AggState::Literal(_)) => Ok(MapStrategy::Nothing),
         }
     }
-}
-
-// Utility to create partitions and cache keys
-pub fn window_function_format_order_by(to: &mut String, e: &Expr, k: &SortOptions) {
-    write!(to, "_PL_{:?}{}_{}", e, k.descending, k.nulls_last).unwrap();
-}
-
-impl PhysicalExpr for WindowExpr {
-    // Note: this was first implemented with expression evaluation but this performed really bad.
-    // Therefore we choose the group_by -> apply -> self join approach
-
-    // This first cached the group_by and the join tuples, but rayon under a mutex leads to deadlocks:
-    // https://github.com/rayon-rs/rayon/issues/592
-    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
-        // This method does the following:
-        // 1. determine group_by tuples based on the group_column
-        // 2. apply an aggregation function
-        // 3. join the results back to the original dataframe
-        //    this stores all group values on the original df size
-        //
-        //      we have several strategies for this
-        //      - 3.1 JOIN
-        //          Use a join for aggregations like
-        //              `sum("foo").over("groups")`
-        //          and explicit `list` aggregations
-        //              `(col("x").sum() * col("y")).list().over("groups")`
-        //
-        //      - 3.2 EXPLODE
-        //          Explicit list aggregations that are followed by `over().flatten()`
-        //          # the fastest method to do things over groups when the groups are sorted.
-        //          # note that it will require an explicit `list()` call from now on.
-        //              `(col("x").sum() * col("y")).list().over("groups").flatten()`
-        //
-        //      - 3.3. MAP to original locations
-        //          This will be done for list aggregations that are not explicitly aggregated as list
-        //              `(col("x").sum() * col("y")).over("groups")
-        //          This can be used to reverse, sort, shuffle etc. the values in a group
-
-        // 4. select the final column and return
-
-        if df.is_empty() {
-            let field = self.phys_function.to_field(df.schema())?;
-            match self.mapping {
-                WindowMapping::Join => {
-                    return Ok(Column::full_null(
-                        field.name().clone(),
-                        0,
-                        &DataType::List(Box::new(field.dtype().clone())),
-                    ));
-                },
-                _ => {
-                    return Ok(Column::full_null(field.name().clone(), 0, field.dtype()));
-                },
-            }
-        }
-
-        let group_by_columns = self
-            .group_by
-            .iter()
-            .map(|e| e.evaluate(df, state))
-            .collect::<PolarsResult<Vec<_>>>()?;
 
+    /// Whether the groups need to be sorted for the chosen mapping strategy.
+    fn needs_sorted_groups(&self, group_by_columns: &[Column]) -> bool {
         // if the keys are sorted
         let sorted_keys = group_by_columns.iter().all(|s| {
             matches!(
@@ -403,7 +345,7 @@ impl PhysicalExpr for WindowExpr {
         let explicit_list_agg = self.is_explicit_list_agg();
 
         // if we flatten this column we need to make sure the groups are sorted.
-        let mut sort_groups = matches!(self.mapping, WindowMapping::Explode) ||
+        let sort_groups = matches!(self.mapping, WindowMapping::Explode) ||
             // if not
             //      `col().over()`
             // and not
@@ -416,51 +358,81 @@ impl PhysicalExpr for WindowExpr {
 
         // overwrite sort_groups for some expressions
         // TODO: fully understand the rationale is here.
-        if self.has_different_group_sources {
-            sort_groups = true
-        }
-
-        let create_groups = || {
-            let gb = df.group_by_with_series(group_by_columns.clone(), true, sort_groups)?;
-            let mut groups = gb.take_groups();
-
-            if let Some((order_by, options)) = &self.order_by {
-                let order_by = order_by.evaluate(df, state)?;
-                polars_ensure!(order_by.len() == df.height(), ShapeMismatch: "the order by expression evaluated to a length: {} that doesn't match the input DataFrame: {}", order_by.len(), df.height());
-                groups = update_groups_sort_by(&groups, order_by.as_materialized_series(), options)?
-                    .into_sliceable()
-            }
+        sort_groups || self.has_different_group_sources
+    }
 
-            let out: PolarsResult<GroupPositions> = Ok(groups);
-            out
+    /// Sort the rows within every group by the `order_by` keys, if any.
+    fn order_groups(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        groups: GroupPositions,
+    ) -> PolarsResult<GroupPositions> {
+        let Some(order_by) = &self.order_by else {
+            return Ok(groups);
         };
+        let mut by = Vec::with_capacity(order_by.len());
+        let mut options = Vec::with_capacity(order_by.len());
+        for (e, opts) in order_by {
+            let s = e.evaluate(df, state)?;
+            polars_ensure!(s.len() == df.height(), ShapeMismatch: "the order by expression evaluated to a length: {} that doesn't match the input DataFrame: {}", s.len(), df.height());
+            by.push(s.take_materialized_series());
+            options.push(*opts);
+        }
+        Ok(update_groups_sort_by(&groups, &by, &options)?.into_sliceable())
+    }
 
-        // Try to get cached grouptuples
-        let (mut groups, cache_key) = if state.cache_window() {
-            let mut cache_key = String::with_capacity(32 * group_by_columns.len());
+    /// The key under which the groups, join tuples and mapping indices of this window are cached.
+    ///
+    /// The key is derived from the sorted set of group-by column names and the `order_by`, so
+    /// windows over the same keys share their cached groups regardless of the branch they are
+    /// evaluated in. This is only sound if group-by columns with the same names hold identical
+    /// values, which isn't guaranteed if the groups come from different sources. In that case the
+    /// key is scoped to the branch.
+    fn window_cache_key(
+        &self,
+        group_by_columns: &[Column],
+        state: &ExecutionState,
+    ) -> PolarsResult<String> {
+        let mut cache_key = String::with_capacity(32 * group_by_columns.len());
+        if self.has_different_group_sources {
             write!(&mut cache_key, "{}", state.branch_idx).unwrap();
-            for s in &group_by_columns {
-                cache_key.push_str(s.name());
-            }
-            if let Some((e, options)) = &self.order_by {
-                let e = match e.as_expression() {
-                    Some(e) => e,
+        }
+        let mut names = group_by_columns
+            .iter()
+            .map(|s| s.name().as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        for name in names {
+            // Separate the names so that e.g. `["ab", "c"]` and `["a", "bc"]` differ.
+            write!(&mut cache_key, "{}:{name}", name.len()).unwrap();
+        }
+        if let Some(order_by) = &self.order_by {
+            let order_by = order_by
+                .iter()
+                .map(|(e, options)| match e.as_expression() {
+                    Some(e) => Ok((e, options)),
                     None => {
                         polars_bail!(InvalidOperation: "cannot order by this expression in window function")
                     },
-                };
-                window_function_format_order_by(&mut cache_key, e, options)
-            }
-
-            let groups = match state.window_cache.get_groups(&cache_key) {
-                Some(groups) => groups,
-                None => create_groups()?,
-            };
-            (groups, cache_key)
-        } else {
-            (create_groups()?, "".to_string())
-        };
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            window_function_format_order_by(&mut cache_key, order_by)
+        }
+        Ok(cache_key)
+    }
 
+    /// Run the aggregation over the given groups and map the results back to the rows of `df`.
+    fn evaluate_with_groups(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        group_by_columns: Vec<Column>,
+        mut groups: GroupPositions,
+        cache_key: String,
+        sort_groups: bool,
+    ) -> PolarsResult<Column> {
         // 2. create GroupBy object and apply aggregation
         let apply_columns = self.apply_columns.clone();
 
@@ -598,6 +570,94 @@ impl PhysicalExpr for WindowExpr {
             },
         }
     }
+}
+
+// Utility to create partitions and cache keys
+pub fn window_function_format_order_by<'a>(
+    to: &mut String,
+    order_by: impl IntoIterator<Item = (&'a Expr, &'a SortOptions)>,
+) {
+    for (e, k) in order_by {
+        write!(to, "_PL_{:?}{}_{}", e, k.descending, k.nulls_last).unwrap();
+    }
+}
+
+impl PhysicalExpr for WindowExpr {
+    // Note: this was first implemented with expression evaluation but this performed really bad.
+    // Therefore we choose the group_by -> apply -> self join approach
+
+    // This first cached the group_by and the join tuples, but rayon under a mutex leads to deadlocks:
+    // https://github.com/rayon-rs/rayon/issues/592
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        // This method does the following:
+        // 1. determine group_by tuples based on the group_column
+        // 2. apply an aggregation function
+        // 3. join the results back to the original dataframe
+        //    this stores all group values on the original df size
+        //
+        //      we have several strategies for this
+        //      - 3.1 JOIN
+        //          Use a join for aggregations like
+        //              `sum("foo").over("groups")`
+        //          and explicit `list` aggregations
+        //              `(col("x").sum() * col("y")).list().over("groups")`
+        //
+        //      - 3.2 EXPLODE
+        //          Explicit list aggregations that are followed by `over().flatten()`
+        //          # the fastest method to do things over groups when the groups are sorted.
+        //          # note that it will require an explicit `list()` call from now on.
+        //              `(col("x").sum() * col("y")).list().over("groups").flatten()`
+        //
+        //      - 3.3. MAP to original locations
+        //          This will be done for list aggregations that are not explicitly aggregated as list
+        //              `(col("x").sum() * col("y")).over("groups")
+        //          This can be used to reverse, sort, shuffle etc. the values in a group
+
+        // 4. select the final column and return
+
+        if df.is_empty() {
+            let field = self.phys_function.to_field(df.schema())?;
+            match self.mapping {
+                WindowMapping::Join => {
+                    return Ok(Column::full_null(
+                        field.name().clone(),
+                        0,
+                        &DataType::List(Box::new(field.dtype().clone())),
+                    ));
+                },
+                _ => {
+                    return Ok(Column::full_null(field.name().clone(), 0, field.dtype()));
+                },
+            }
+        }
+
+        let group_by_columns = self
+            .group_by
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let sort_groups = self.needs_sorted_groups(&group_by_columns);
+
+        let create_groups = || {
+            let gb = df.group_by_with_series(group_by_columns.clone(), true, sort_groups)?;
+            self.order_groups(df, state, gb.take_groups())
+        };
+
+        // Try to get cached grouptuples
+        let (groups, cache_key) = if state.cache_window() {
+            let cache_key = self.window_cache_key(&group_by_columns, state)?;
+            let groups = match state.window_cache.get_groups(&cache_key) {
+                Some(groups) => groups,
+                None => create_groups()?,
+            };
+            (groups, cache_key)
+        } else {
+            (create_groups()?, "".to_string())
+        };
+
+        self.evaluate_with_groups(df, state, group_by_columns, groups, cache_key, sort_groups)
+    }
 
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
         self.function.to_field(input_schema, Context::Default)
@@ -794,3 +854,107 @@ fn set_numeric<T: PolarsNumericType>(
         Series::try_from((ca.name().clone(), arr.boxed())).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(df: &DataFrame, name: &str) -> Arc<dyn PhysicalExpr> {
+        Arc::new(ColumnExpr::new(name.into(), col(name), df.schema().clone()))
+    }
+
+    /// `col("v").first().over("g")`, ordered by `o` within every group.
+    fn first_over_g_by_o(df: &DataFrame, descending: bool) -> WindowExpr {
+        let column = |name| column(df, name);
+        let function = col("v").first();
+        WindowExpr {
+            group_by: vec![column("g")],
+            order_by: Some(vec![(
+                column("o"),
+                SortOptions {
+                    descending,
+                    ..Default::default()
+                },
+            )]),
+            apply_columns: vec!["v".into()],
+            phys_function: Arc::new(AggregationExpr::new(
+                column("v"),
+                AggregationType {
+                    groupby: GroupByMethod::First,
+                    allow_threading: false,
+                },
+                None,
+            )),
+            expr: function.clone().over([col("g")]),
+            function,
+            mapping: WindowMapping::GroupsToRows,
+            has_different_group_sources: false,
+        }
+    }
+
+    fn values(c: &Column) -> Vec<Option<i32>> {
+        c.as_materialized_series()
+            .i32()
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_order_by() -> PolarsResult<()> {
+        let df = df![
+            "g" => [1, 1, 2, 2, 1],
+            "o" => [3, 1, 2, 0, 2],
+            "v" => [10, 11, 12, 13, 14],
+        ]?;
+
+        for (descending, expected) in [(false, [11, 11, 13, 13, 11]), (true, [10, 10, 12, 12, 10])]
+        {
+            let window = first_over_g_by_o(&df, descending);
+            let expected = expected.map(Some).to_vec();
+
+            let mut state = ExecutionState::new();
+            state.remove_cache_window_flag();
+            assert_eq!(values(&window.evaluate(&df, &state)?), expected);
+            assert_eq!(
+                values(&window.evaluate(&df, &ExecutionState::new())?),
+                expected
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_cache_shared_across_branches() -> PolarsResult<()> {
+        let df = df![
+            "g" => [1, 1, 2, 2, 1],
+            "h" => [0, 0, 0, 1, 1],
+            "o" => [3, 1, 2, 0, 2],
+            "v" => [10, 11, 12, 13, 14],
+        ]?;
+        let mut window = first_over_g_by_o(&df, false);
+        window.group_by = vec![column(&df, "g"), column(&df, "h")];
+        let mut other = first_over_g_by_o(&df, false);
+        other.group_by = vec![column(&df, "h"), column(&df, "g")];
+
+        // Cache groups that differ from the ones the windows compute, to see whether they are used.
+        let state = ExecutionState::new();
+        let gh = [df.column("g")?.clone(), df.column("h")?.clone()];
+        let key = window.window_cache_key(&gh, &state)?;
+        state
+            .window_cache
+            .insert_groups(key, df.group_by(["v"])?.take_groups());
+
+        // Another branch reuses them, also with the keys in a different order.
+        let mut branch = state.clone();
+        branch.branch_idx += 1;
+        let out = other.evaluate(&df, &branch)?;
+        assert_eq!(values(&out), [10, 11, 12, 13, 14].map(Some));
+
+        // Groups from different sources are only shared within a branch.
+        other.has_different_group_sources = true;
+        let out = other.evaluate(&df, &branch)?;
+        assert_eq!(values(&out), [11, 11, 12, 13, 14].map(Some));
+        Ok(())
+    }
+}
@@ -183,17 +183,52 @@ fn create_physical_expr_inner(
             )?;
 
             let order_by = order_by
+                .as_ref()
                 .map(|(node, options)| {
-                    PolarsResult::Ok((
-                        create_physical_expr_inner(
-                            node,
-                            Context::Aggregation,
-                            expr_arena,
-                            schema,
-                            state,
-                        )?,
-                        options,
-                    ))
+                    // Multiple `order_by` expressions are combined in a struct. Sort by its
+                    // fields directly so that ties in the first key are broken by the next.
+                    let nodes = match expr_arena.get(*node) {
+                        #[cfg(feature = "dtype-struct")]
+                        Function {
+                            input,
+                            function: IRFunctionExpr::AsStruct,
+                            ..
+                        } => input.iter().map(|e| e.node()).collect(),
+                        _ => vec![*node],
+                    };
+                    let n_desc = options.descending.len();
+                    polars_ensure!(
+                        n_desc == nodes.len() || n_desc == 1,
+                        ComputeError: "the length of `descending` ({}) does not match the length of `order_by` ({})", n_desc, nodes.len()
+                    );
+                    let n_nulls_last = options.nulls_last.len();
+                    polars_ensure!(
+                        n_nulls_last == nodes.len() || n_nulls_last == 1,
+                        ComputeError: "the length of `nulls_last` ({}) does not match the length of `order_by` ({})", n_nulls_last, nodes.len()
+                    );
+                    nodes
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, node)| {
+                            let options = SortOptions {
+                                descending: options.descending[i.min(n_desc - 1)],
+                                nulls_last: options.nulls_last[i.min(n_nulls_last - 1)],
+                                multithreaded: options.multithreaded,
+                                maintain_order: options.maintain_order,
+                                limit: options.limit,
+                            };
+                            PolarsResult::Ok((
+                                create_physical_expr_inner(
+                                    node,
+                                    Context::Aggregation,
+                                    expr_arena,
+                                    schema,
+                                    state,
+                                )?,
+                                options,
+                            ))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
                 })
                 .transpose()?;
 
@@ -363,7 +398,8 @@ fn create_physical_expr_inner(
 
             match ctxt {
                 Context::Default if !matches!(agg, IRAggExpr::Quantile { .. }) => {
-                    use {GroupByMethod as GBM, IRAggExpr as I};
+                    use GroupByMethod as GBM;
+                    use IRAggExpr as I;
 
                     let groupby = match agg {
                         I::Min { propagate_nans, .. } if *propagate_nans => GBM::NanMin,
@@ -529,6 +565,20 @@ fn create_physical_expr_inner(
                 evaluation_is_scalar,
             )))
         },
+        #[cfg(feature = "rank")]
+        Function {
+            input,
+            function: IRFunctionExpr::Ntile { n },
+            ..
+        } => {
+            let input =
+                create_physical_expr_inner(input[0].node(), ctxt, expr_arena, schema, state)?;
+            Ok(Arc::new(NtileExpr::new(
+                input,
+                *n,
+                node_to_expr(expression, expr_arena),
+            )))
+        },
         Function {
             input,
             function,
@@ -9,6 +9,8 @@ mod object_store_setup;
 pub mod options;
 #[cfg(feature = "cloud")]
 mod polars_object_store;
+#[cfg(feature = "aws")]
+mod sts;
 
 #[cfg(feature = "cloud")]
 pub use adaptors::*;
@@ -38,6 +38,7 @@ fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> Vec<u8> {
              #[cfg(feature = "file_cache")]
              file_cache_ttl,
              config,
+             dns_timeout,
              #[cfg(feature = "cloud")]
              credential_provider,
          }| {
@@ -46,6 +47,7 @@ fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> Vec<u8> {
                 #[cfg(feature = "file_cache")]
                 file_cache_ttl: *file_cache_ttl,
                 config: config.clone(),
+                dns_timeout: *dns_timeout,
                 #[cfg(feature = "cloud")]
                 credential_provider: credential_provider.as_ref().map_or(0, |x| x.func_addr()),
             }
@@ -80,6 +82,7 @@ fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> Vec<u8> {
         #[cfg(feature = "file_cache")]
         file_cache_ttl: u64,
         config: Option<CloudConfig>,
+        dns_timeout: Option<std::time::Duration>,
         #[cfg(feature = "cloud")]
         credential_provider: usize,
     }
@@ -25,6 +25,8 @@ use polars_error::*;
 #[cfg(feature = "aws")]
 use polars_utils::cache::LruCache;
 #[cfg(feature = "http")]
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "http")]
 use reqwest::header::HeaderMap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -72,7 +74,12 @@ pub(crate) enum CloudConfig {
         Configs<GoogleConfigKey>,
     ),
     #[cfg(feature = "http")]
-    Http { headers: Vec<(String, String)> },
+    Http {
+        headers: Vec<(String, String)>,
+        /// Default revision for Hugging Face paths that don't specify one.
+        #[cfg_attr(feature = "serde", serde(default))]
+        revision: Option<PlSmallStr>,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
@@ -84,6 +91,9 @@ pub struct CloudOptions {
     #[cfg(feature = "file_cache")]
     pub file_cache_ttl: u64,
     pub(crate) config: Option<CloudConfig>,
+    /// Timeout for establishing a connection, including DNS resolution. Disabled if `None`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dns_timeout: Option<std::time::Duration>,
     #[cfg(feature = "cloud")]
     /// Note: In most cases you will want to access this via [`CloudOptions::initialized_credential_provider`]
     /// rather than directly.
@@ -103,6 +113,7 @@ impl CloudOptions {
             #[cfg(feature = "file_cache")]
             file_cache_ttl: get_env_file_cache_ttl(),
             config: None,
+            dns_timeout: None,
             #[cfg(feature = "cloud")]
             credential_provider: None,
         });
@@ -148,6 +159,29 @@ where
         .collect::<Configs<T>>())
 }
 
+/// Set `key` to `value`, overwriting an existing value of `key`.
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+fn set_config<K: PartialEq>(configs: &mut Configs<K>, key: K, value: &str) {
+    match configs.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, v)) => *v = value.to_string(),
+        None => configs.push((key, value.to_string())),
+    }
+}
+
+/// Set the endpoint, allowing plain HTTP if it is an `http://` endpoint.
+#[cfg(any(feature = "aws", feature = "azure"))]
+fn set_endpoint_config<K: PartialEq>(
+    configs: &mut Configs<K>,
+    endpoint_key: K,
+    allow_http_key: K,
+    endpoint: &str,
+) {
+    set_config(configs, endpoint_key, endpoint);
+    if endpoint.starts_with("http://") {
+        set_config(configs, allow_http_key, "true");
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CloudType {
     Aws,
@@ -218,6 +252,46 @@ pub(crate) fn parse_url(input: &str) -> std::result::Result<url::Url, url::Parse
     })
 }
 
+/// An ADLS Gen2 url `abfs[s]://{container}@{account}.dfs.{suffix}/{path}` split into parts that
+/// [`MicrosoftAzureBuilder`] understands for every Azure cloud.
+#[cfg(feature = "azure")]
+#[derive(Debug, PartialEq)]
+struct AdlsGen2Url {
+    /// The url in the `az://{container}/{path}` format.
+    url: String,
+    account: String,
+    /// The blob endpoint if the account isn't in the public Azure cloud.
+    endpoint: Option<String>,
+}
+
+#[cfg(feature = "azure")]
+impl AdlsGen2Url {
+    /// Returns `None` if `url` isn't an ADLS Gen2 url of a storage account.
+    fn parse(url: &str) -> Option<Self> {
+        let rest = url
+            .strip_prefix("abfss://")
+            .or_else(|| url.strip_prefix("abfs://"))?;
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let (container, host) = authority.split_once('@')?;
+        let (account, domain) = host.split_once('.')?;
+        let suffix = domain
+            .strip_prefix("dfs.")
+            .or_else(|| domain.strip_prefix("blob."))?;
+        // Fabric (OneLake) urls are handled by `object_store`.
+        if container.is_empty() || account.is_empty() || !suffix.starts_with("core.") {
+            return None;
+        }
+
+        let endpoint =
+            (suffix != "core.windows.net").then(|| format!("https://{account}.blob.{suffix}"));
+        Some(Self {
+            url: format!("az://{container}{path}"),
+            account: account.to_string(),
+            endpoint,
+        })
+    }
+}
+
 impl FromStr for CloudType {
     type Err = PolarsError;
 
@@ -242,15 +316,18 @@ fn get_retry_config(max_retries: usize) -> RetryConfig {
 }
 
 #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
-pub(super) fn get_client_options() -> ClientOptions {
-    ClientOptions::new()
+pub(super) fn get_client_options(connect_timeout: Option<std::time::Duration>) -> ClientOptions {
+    let opts = ClientOptions::new()
         // We set request timeout super high as the timeout isn't reset at ACK,
         // but starts from the moment we start downloading a body.
         // https://docs.rs/reqwest/latest/reqwest/struct.ClientBuilder.html#method.timeout
         .with_timeout_disabled()
+        .with_allow_http(true);
+    match connect_timeout {
+        Some(timeout) => opts.with_connect_timeout(timeout),
         // Concurrency can increase connection latency, so set to None, similar to default.
-        .with_connect_timeout_disabled()
-        .with_allow_http(true)
+        None => opts.with_connect_timeout_disabled(),
+    }
 }
 
 #[cfg(feature = "aws")]
@@ -286,6 +363,30 @@ fn read_config(
     Some(())
 }
 
+/// Get the `key = value` pairs of the `[section]` of an INI formatted file.
+///
+/// Returns `None` if the section does not exist.
+#[cfg(feature = "aws")]
+fn parse_ini_section<'a>(content: &'a str, section: &str) -> Option<Vec<(&'a str, &'a str)>> {
+    let mut lines = content.lines().map(str::trim);
+    lines.find(|line| {
+        line.strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+            .is_some_and(|name| name.trim() == section)
+    })?;
+
+    Some(
+        lines
+            .take_while(|line| !line.starts_with('['))
+            .filter(|line| !line.is_empty() && !line.starts_with(['#', ';']))
+            .filter_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                Some((key.trim(), value.trim()))
+            })
+            .collect(),
+    )
+}
+
 impl CloudOptions {
     /// Set the maximum number of retries.
     pub fn with_max_retries(mut self, max_retries: usize) -> Self {
@@ -293,6 +394,15 @@ impl CloudOptions {
         self
     }
 
+    /// Set a timeout for establishing connections to the object store.
+    ///
+    /// The timeout covers both DNS resolution and the TCP connect, which helps in environments
+    /// where resolving the endpoint can hang. Disabled by default.
+    pub fn with_dns_resolution_timeout(mut self, duration: std::time::Duration) -> Self {
+        self.dns_timeout = Some(duration);
+        self
+    }
+
     #[cfg(feature = "cloud")]
     pub fn with_credential_provider(
         mut self,
@@ -302,6 +412,94 @@ impl CloudOptions {
         self
     }
 
+    /// Point the connection to a custom endpoint, e.g. an emulator like MinIO, Azurite or
+    /// fake-gcs-server.
+    ///
+    /// The endpoint setting of the provider of the current config is set, so this must be called
+    /// after [`CloudOptions::with_aws`], [`CloudOptions::with_azure`] or
+    /// [`CloudOptions::with_gcp`]. Plain `http://` endpoints are allowed as well. GCP has no
+    /// endpoint key, so its service account is replaced by an emulator one with the endpoint as
+    /// base URL and OAuth disabled. Other configs have no endpoint setting and are left unchanged.
+    #[cfg(feature = "cloud")]
+    pub fn with_custom_endpoint(mut self, endpoint: &str) -> Self {
+        match &mut self.config {
+            #[cfg(feature = "aws")]
+            Some(CloudConfig::Aws(configs)) => set_endpoint_config(
+                configs,
+                AmazonS3ConfigKey::Endpoint,
+                AmazonS3ConfigKey::Client(object_store::ClientConfigKey::AllowHttp),
+                endpoint,
+            ),
+            #[cfg(feature = "azure")]
+            Some(CloudConfig::Azure(configs)) => set_endpoint_config(
+                configs,
+                AzureConfigKey::Endpoint,
+                AzureConfigKey::Client(object_store::ClientConfigKey::AllowHttp),
+                endpoint,
+            ),
+            #[cfg(feature = "gcp")]
+            Some(CloudConfig::Gcp(configs)) => {
+                // A service account path and key can't both be set.
+                configs.retain(|(k, _)| *k != GoogleConfigKey::ServiceAccount);
+                let service_account = serde_json::json!({
+                    "gcs_base_url": endpoint,
+                    "disable_oauth": true,
+                    "client_email": "",
+                    "private_key": "",
+                });
+                set_config(
+                    configs,
+                    GoogleConfigKey::ServiceAccountKey,
+                    &service_account.to_string(),
+                );
+                if endpoint.starts_with("http://") {
+                    set_config(
+                        configs,
+                        GoogleConfigKey::Client(object_store::ClientConfigKey::AllowHttp),
+                        "true",
+                    );
+                }
+            },
+            _ => {},
+        }
+        self
+    }
+
+    /// Configure the connection to an S3-compatible service, e.g. Cloudflare R2 or DigitalOcean
+    /// Spaces.
+    ///
+    /// Sets the `endpoint`, the `region` requests are signed for and whether buckets are
+    /// addressed in the path (`path_style`) instead of the host name. Previously set AWS options
+    /// are kept; this errors if another provider is configured.
+    #[cfg(feature = "aws")]
+    pub fn with_sigv4_signing(
+        mut self,
+        endpoint: &str,
+        region: &str,
+        path_style: bool,
+    ) -> PolarsResult<Self> {
+        let config = self.config.get_or_insert_with(|| CloudConfig::Aws(vec![]));
+        let CloudConfig::Aws(configs) = config else {
+            polars_bail!(
+                InvalidOperation: "cannot configure SigV4 signing for endpoint '{}': only AWS \
+                configs support it", endpoint
+            )
+        };
+        set_config(configs, AmazonS3ConfigKey::Region, region);
+        set_config(
+            configs,
+            AmazonS3ConfigKey::VirtualHostedStyleRequest,
+            if path_style { "false" } else { "true" },
+        );
+        set_endpoint_config(
+            configs,
+            AmazonS3ConfigKey::Endpoint,
+            AmazonS3ConfigKey::Client(object_store::ClientConfigKey::AllowHttp),
+            endpoint,
+        );
+        Ok(self)
+    }
+
     /// Set the configuration for AWS connections. This is the preferred API from rust.
     #[cfg(feature = "aws")]
     pub fn with_aws<I: IntoIterator<Item = (AmazonS3ConfigKey, impl Into<String>)>>(
@@ -314,6 +512,116 @@ impl CloudOptions {
         self
     }
 
+    /// Create [`CloudOptions`] for AWS from a named profile in `~/.aws/credentials` and
+    /// `~/.aws/config`.
+    ///
+    /// Values in `~/.aws/credentials` take precedence over those in `~/.aws/config`.
+    #[cfg(feature = "aws")]
+    pub fn from_profile_name(profile: &str) -> PolarsResult<Self> {
+        use crate::path_utils::resolve_homedir;
+
+        let read = |path: &str| std::fs::read_to_string(resolve_homedir(&path)).unwrap_or_default();
+        let credentials = read("~/.aws/credentials");
+        let config = read("~/.aws/config");
+
+        // Apart from the default profile, `~/.aws/config` prefixes the sections with `profile`.
+        let config_section = if profile == "default" {
+            profile.to_string()
+        } else {
+            format!("profile {profile}")
+        };
+        let credentials = parse_ini_section(&credentials, profile);
+        let config = parse_ini_section(&config, &config_section)
+            .or_else(|| parse_ini_section(&config, profile));
+
+        polars_ensure!(
+            credentials.is_some() || config.is_some(),
+            ComputeError: "AWS profile '{}' not found in ~/.aws/credentials or ~/.aws/config",
+            profile
+        );
+
+        let mut configs: Vec<(AmazonS3ConfigKey, String)> = vec![];
+        for (key, value) in config.into_iter().chain(credentials).flatten() {
+            let key = match key {
+                "aws_access_key_id" => AmazonS3ConfigKey::AccessKeyId,
+                "aws_secret_access_key" => AmazonS3ConfigKey::SecretAccessKey,
+                "aws_session_token" => AmazonS3ConfigKey::Token,
+                "region" => AmazonS3ConfigKey::Region,
+                _ => continue,
+            };
+            match configs.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, v)) => *v = value.to_string(),
+                None => configs.push((key, value.to_string())),
+            }
+        }
+
+        Ok(Self::default().with_aws(configs))
+    }
+
+    /// Create [`CloudOptions`] for AWS by assuming the IAM role `role_arn` through STS.
+    ///
+    /// The `AssumeRole` request is signed with the credentials from the environment or the
+    /// default profile. The temporary credentials are stored in the AWS config; they are renewed
+    /// by calling `AssumeRole` again shortly before they expire.
+    #[cfg(feature = "aws")]
+    pub async fn from_iam_role(
+        role_arn: &str,
+        external_id: Option<&str>,
+        session_name: &str,
+    ) -> PolarsResult<Self> {
+        use std::sync::{Arc, Mutex};
+
+        use super::credential_provider::ObjectStoreCredential;
+        use super::sts::assume_role;
+
+        // Renew the credentials this many seconds before they expire.
+        const EXPIRY_MARGIN: u64 = 300;
+
+        let role = assume_role(role_arn, external_id, session_name).await?;
+        let mut configs = vec![
+            (
+                AmazonS3ConfigKey::AccessKeyId,
+                role.credential.key_id.clone(),
+            ),
+            (
+                AmazonS3ConfigKey::SecretAccessKey,
+                role.credential.secret_key.clone(),
+            ),
+        ];
+        if let Some(token) = &role.credential.token {
+            configs.push((AmazonS3ConfigKey::Token, token.clone()));
+        }
+
+        // The first call of the credential provider returns the credentials fetched above, later
+        // calls only happen once those have expired.
+        let initial = Arc::new(Mutex::new(Some(role)));
+        let (role_arn, external_id, session_name) = (
+            role_arn.to_string(),
+            external_id.map(|v| v.to_string()),
+            session_name.to_string(),
+        );
+        let credential_provider = PlCredentialProvider::from_func(move || {
+            let initial = initial.clone();
+            let (role_arn, external_id, session_name) =
+                (role_arn.clone(), external_id.clone(), session_name.clone());
+            Box::pin(async move {
+                let cached = initial.lock().unwrap().take();
+                let role = match cached {
+                    Some(role) => role,
+                    None => assume_role(&role_arn, external_id.as_deref(), &session_name).await?,
+                };
+                Ok((
+                    ObjectStoreCredential::Aws(Arc::new(role.credential)),
+                    role.expiry.saturating_sub(EXPIRY_MARGIN),
+                ))
+            })
+        });
+
+        Ok(Self::default()
+            .with_aws(configs)
+            .with_credential_provider(Some(credential_provider)))
+    }
+
     /// Build the [`object_store::ObjectStore`] implementation for AWS.
     #[cfg(feature = "aws")]
     pub async fn build_aws(
@@ -327,7 +635,7 @@ impl CloudOptions {
             self.initialized_credential_provider(clear_cached_credentials)?;
 
         let mut builder = AmazonS3Builder::from_env()
-            .with_client_options(get_client_options())
+            .with_client_options(get_client_options(self.dns_timeout))
             .with_url(url);
 
         if let Some(credential_provider) = &opt_credential_provider {
@@ -492,8 +800,20 @@ impl CloudOptions {
 
         // The credential provider `self.credentials` is prioritized if it is set. We also need
         // `from_env()` as it may source environment configured storage account name.
-        let mut builder =
-            MicrosoftAzureBuilder::from_env().with_client_options(get_client_options());
+        let mut builder = MicrosoftAzureBuilder::from_env()
+            .with_client_options(get_client_options(self.dns_timeout));
+
+        // Normalize ADLS Gen2 urls, explicitly set configuration takes precedence.
+        let adls_gen2 = AdlsGen2Url::parse(url);
+        let url = if let Some(adls_gen2) = &adls_gen2 {
+            builder = builder.with_account(&adls_gen2.account);
+            if let Some(endpoint) = &adls_gen2.endpoint {
+                builder = builder.with_endpoint(endpoint.clone());
+            }
+            adls_gen2.url.as_str()
+        } else {
+            url
+        };
 
         if let Some(options) = &self.config {
             let CloudConfig::Azure(options) = options else {
@@ -555,7 +875,7 @@ impl CloudOptions {
             GoogleCloudStorageBuilder::new()
         };
 
-        let mut builder = builder.with_client_options(get_client_options());
+        let mut builder = builder.with_client_options(get_client_options(self.dns_timeout));
 
         if let Some(options) = &self.config {
             let CloudConfig::Gcp(options) = options else {
@@ -581,13 +901,35 @@ impl CloudOptions {
         Ok(out)
     }
 
+    /// Set the default revision (branch, tag or commit SHA) of Hugging Face paths.
+    ///
+    /// This is used for `hf://` paths that don't pin a revision with `@{revision}`. Errors if a
+    /// config of another provider is set.
+    #[cfg(feature = "http")]
+    pub fn with_hf_dataset_revision(
+        mut self,
+        revision: impl Into<PlSmallStr>,
+    ) -> PolarsResult<Self> {
+        let config = self.config.get_or_insert_with(|| CloudConfig::Http {
+            headers: vec![],
+            revision: None,
+        });
+        let CloudConfig::Http { revision: r, .. } = config else {
+            polars_bail!(
+                InvalidOperation: "cannot set a Hugging Face revision on a non-HTTP cloud config"
+            )
+        };
+        *r = Some(revision.into());
+        Ok(self)
+    }
+
     #[cfg(feature = "http")]
     pub fn build_http(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
         let out = object_store::http::HttpBuilder::new()
             .with_url(url)
             .with_client_options({
-                let mut opts = super::get_client_options();
-                if let Some(CloudConfig::Http { headers }) = &self.config {
+                let mut opts = super::get_client_options(self.dns_timeout);
+                if let Some(CloudConfig::Http { headers, .. }) = &self.config {
                     opts = opts.with_default_headers(try_build_http_header_map_from_items_slice(
                         headers.as_slice(),
                     )?);
@@ -650,18 +992,33 @@ impl CloudOptions {
 
                     let mut this = Self::default();
                     let mut token = None;
+                    let mut revision = None;
                     let verbose = config::verbose();
 
-                    for (i, (k, v)) in config.into_iter().enumerate() {
+                    for (k, v) in config {
                         let (k, v) = (k.as_ref(), v.into());
 
-                        if i == 0 && k == "token" {
-                            if verbose {
-                                eprintln!("HF token sourced from storage_options");
-                            }
-                            token = Some(v);
-                        } else {
-                            polars_bail!(ComputeError: "unknown configuration key for HF: {}", k)
+                        match k {
+                            "token" => {
+                                polars_ensure!(
+                                    token.is_none(),
+                                    ComputeError: "duplicate configuration key for HF: {}", k
+                                );
+                                if verbose {
+                                    eprintln!("HF token sourced from storage_options");
+                                }
+                                token = Some(v);
+                            },
+                            "revision" => {
+                                polars_ensure!(
+                                    revision.is_none(),
+                                    ComputeError: "duplicate configuration key for HF: {}", k
+                                );
+                                revision = Some(v);
+                            },
+                            _ => {
+                                polars_bail!(ComputeError: "unknown configuration key for HF: {}", k)
+                            },
                         }
                     }
 
@@ -699,8 +1056,12 @@ impl CloudOptions {
                     if let Some(v) = token {
                         this.config = Some(CloudConfig::Http {
                             headers: vec![("Authorization".into(), format!("Bearer {v}"))],
+                            revision: None,
                         })
                     }
+                    if let Some(revision) = revision {
+                        this = this.with_hf_dataset_revision(revision)?;
+                    }
 
                     Ok(this)
                 }
@@ -842,4 +1203,215 @@ mod tests {
         );
         assert_eq!(aws_keys.len(), 1);
     }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_parse_ini_section() {
+        use super::parse_ini_section;
+
+        let content = "\
+[default]
+region = eu-west-1
+
+# comment
+[profile dev]
+region=us-east-1
+aws_access_key_id = AKIA
+";
+        assert_eq!(
+            parse_ini_section(content, "default"),
+            Some(vec![("region", "eu-west-1")])
+        );
+        assert_eq!(
+            parse_ini_section(content, "profile dev"),
+            Some(vec![("region", "us-east-1"), ("aws_access_key_id", "AKIA")])
+        );
+        assert_eq!(parse_ini_section(content, "dev"), None);
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_with_custom_endpoint() {
+        use object_store::ClientConfigKey;
+        use object_store::aws::AmazonS3ConfigKey;
+
+        use super::{CloudConfig, CloudOptions};
+
+        let options = CloudOptions::default()
+            .with_aws([(AmazonS3ConfigKey::Endpoint, "https://s3.example.com")])
+            .with_custom_endpoint("http://localhost:9000");
+        let Some(CloudConfig::Aws(configs)) = &options.config else {
+            panic!()
+        };
+        assert_eq!(
+            configs,
+            &[
+                (AmazonS3ConfigKey::Endpoint, "http://localhost:9000".into()),
+                (
+                    AmazonS3ConfigKey::Client(ClientConfigKey::AllowHttp),
+                    "true".into()
+                ),
+            ]
+        );
+
+        let options = CloudOptions::default().with_custom_endpoint("http://localhost:9000");
+        assert!(options.config.is_none());
+    }
+
+    #[cfg(feature = "gcp")]
+    #[test]
+    fn test_with_custom_endpoint_gcp() {
+        use object_store::ClientConfigKey;
+        use object_store::gcp::GoogleConfigKey;
+
+        use super::{CloudConfig, CloudOptions};
+
+        let options = CloudOptions::default()
+            .with_gcp([
+                (GoogleConfigKey::Bucket, "bucket"),
+                (GoogleConfigKey::ServiceAccount, "/tmp/gcs.json"),
+            ])
+            .with_custom_endpoint("http://localhost:4443");
+        let Some(CloudConfig::Gcp(configs)) = &options.config else {
+            panic!()
+        };
+        assert_eq!(configs.len(), 3);
+        assert_eq!(configs[0], (GoogleConfigKey::Bucket, "bucket".into()));
+        assert_eq!(configs[1].0, GoogleConfigKey::ServiceAccountKey);
+        let service_account: serde_json::Value = serde_json::from_str(&configs[1].1).unwrap();
+        assert_eq!(service_account["gcs_base_url"], "http://localhost:4443");
+        assert_eq!(service_account["disable_oauth"], true);
+        assert_eq!(
+            configs[2],
+            (
+                GoogleConfigKey::Client(ClientConfigKey::AllowHttp),
+                "true".into()
+            )
+        );
+    }
+
+    #[cfg(any(feature = "aws", feature = "gcp", feature = "azure", feature = "http"))]
+    #[test]
+    fn test_get_client_options_connect_timeout() {
+        use std::time::Duration;
+
+        use object_store::ClientConfigKey;
+
+        use super::get_client_options;
+
+        let opts = get_client_options(Some(Duration::from_secs(5)));
+        assert_eq!(
+            opts.get_config_value(&ClientConfigKey::ConnectTimeout)
+                .as_deref(),
+            Some("5s")
+        );
+        let opts = get_client_options(None);
+        assert_eq!(
+            opts.get_config_value(&ClientConfigKey::ConnectTimeout),
+            None
+        );
+    }
+
+    #[cfg(feature = "azure")]
+    #[test]
+    fn test_adls_gen2_url() {
+        use super::AdlsGen2Url;
+
+        assert_eq!(
+            AdlsGen2Url::parse("abfss://container@account.dfs.core.windows.net/a/b.parquet"),
+            Some(AdlsGen2Url {
+                url: "az://container/a/b.parquet".into(),
+                account: "account".into(),
+                endpoint: None,
+            })
+        );
+        assert_eq!(
+            AdlsGen2Url::parse("abfs://container@account.dfs.core.chinacloudapi.cn"),
+            Some(AdlsGen2Url {
+                url: "az://container".into(),
+                account: "account".into(),
+                endpoint: Some("https://account.blob.core.chinacloudapi.cn".into()),
+            })
+        );
+        assert!(AdlsGen2Url::parse("abfss://container/a/b.parquet").is_none());
+        assert!(AdlsGen2Url::parse("az://container@account.dfs.core.windows.net/a").is_none());
+        assert!(
+            AdlsGen2Url::parse("abfss://workspace@onelake.dfs.fabric.microsoft.com/a").is_none()
+        );
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_with_sigv4_signing() {
+        use object_store::aws::AmazonS3ConfigKey;
+
+        use super::{CloudConfig, CloudOptions};
+
+        let options = CloudOptions::default()
+            .with_aws([
+                (AmazonS3ConfigKey::Region, "us-east-1"),
+                (AmazonS3ConfigKey::Bucket, "bucket"),
+            ])
+            .with_sigv4_signing("https://account.r2.cloudflarestorage.com", "auto", true)
+            .unwrap();
+        let Some(CloudConfig::Aws(configs)) = &options.config else {
+            panic!()
+        };
+        assert_eq!(
+            configs,
+            &[
+                (AmazonS3ConfigKey::Region, "auto".into()),
+                (AmazonS3ConfigKey::Bucket, "bucket".into()),
+                (AmazonS3ConfigKey::VirtualHostedStyleRequest, "false".into()),
+                (
+                    AmazonS3ConfigKey::Endpoint,
+                    "https://account.r2.cloudflarestorage.com".into()
+                ),
+            ]
+        );
+
+        let options = CloudOptions::default()
+            .with_sigv4_signing("http://localhost:9000", "us-east-1", false)
+            .unwrap();
+        assert!(matches!(options.config, Some(CloudConfig::Aws(_))));
+
+        #[cfg(feature = "azure")]
+        assert!(
+            CloudOptions::default()
+                .with_azure([(object_store::azure::AzureConfigKey::AccountName, "account")])
+                .with_sigv4_signing("http://localhost:9000", "us-east-1", false)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_hf_dataset_revision() {
+        use super::{CloudConfig, CloudOptions};
+
+        let options = CloudOptions::from_untyped_config(
+            "hf://datasets/pola-rs/polars/data.parquet",
+            [("token", "hf_abc"), ("revision", "v1.0")],
+        )
+        .unwrap();
+        let Some(CloudConfig::Http { revision, .. }) = &options.config else {
+            panic!()
+        };
+        assert_eq!(revision.as_deref(), Some("v1.0"));
+
+        let err = CloudOptions::from_untyped_config(
+            "hf://datasets/pola-rs/polars/data.parquet",
+            [("token", "hf_abc"), ("token", "hf_def")],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("duplicate configuration key"));
+
+        #[cfg(feature = "aws")]
+        assert!(
+            CloudOptions::default()
+                .with_aws([(object_store::aws::AmazonS3ConfigKey::Region, "us-east-1")])
+                .with_hf_dataset_revision("v1.0")
+                .is_err()
+        );
+    }
 }
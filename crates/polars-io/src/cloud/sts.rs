@@ -0,0 +1,206 @@
+//! Minimal client for the AWS Security Token Service (STS) `AssumeRole` action.
+//!
+//! Requests are signed with AWS Signature Version 4 using the credentials from the environment
+//! (`AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN`) or, if those aren't set,
+//! the default profile.
+use object_store::aws::{AmazonS3ConfigKey, AwsAuthorizer, AwsCredential};
+use object_store::client::HttpRequest;
+use polars_error::*;
+use reqwest::Method;
+use reqwest::header::{CONTENT_TYPE, HeaderValue};
+use serde::Deserialize;
+
+use super::options::{CloudConfig, CloudOptions};
+
+const STS_VERSION: &str = "2011-06-15";
+
+/// Temporary credentials returned by `AssumeRole`.
+pub(super) struct AssumedRole {
+    pub(super) credential: AwsCredential,
+    /// Expiry time as seconds since UNIX_EPOCH.
+    pub(super) expiry: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleResponse {
+    assume_role_result: AssumeRoleResult,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleResult {
+    credentials: Credentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Credentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ErrorResponse {
+    error: ErrorDetails,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ErrorDetails {
+    message: String,
+}
+
+fn source_credential() -> PolarsResult<AwsCredential> {
+    let env = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+
+    if let (Some(key_id), Some(secret_key)) =
+        (env("AWS_ACCESS_KEY_ID"), env("AWS_SECRET_ACCESS_KEY"))
+    {
+        return Ok(AwsCredential {
+            key_id,
+            secret_key,
+            token: env("AWS_SESSION_TOKEN"),
+        });
+    }
+
+    let profile = env("AWS_PROFILE").unwrap_or_else(|| "default".to_string());
+    let options = CloudOptions::from_profile_name(&profile)?;
+    let configs = match &options.config {
+        Some(CloudConfig::Aws(configs)) => configs.as_slice(),
+        _ => &[],
+    };
+    let get = |key: AmazonS3ConfigKey| {
+        configs
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.clone())
+    };
+    match (
+        get(AmazonS3ConfigKey::AccessKeyId),
+        get(AmazonS3ConfigKey::SecretAccessKey),
+    ) {
+        (Some(key_id), Some(secret_key)) => Ok(AwsCredential {
+            key_id,
+            secret_key,
+            token: get(AmazonS3ConfigKey::Token),
+        }),
+        _ => polars_bail!(
+            ComputeError: "no AWS credentials found to assume the role with; set \
+            AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY or configure profile '{}'",
+            profile
+        ),
+    }
+}
+
+fn parse_response(xml: &str) -> PolarsResult<AssumedRole> {
+    let response: AssumeRoleResponse = quick_xml::de::from_str(xml)
+        .map_err(|e| polars_err!(ComputeError: "invalid STS AssumeRole response: {}", e))?;
+    let credentials = response.assume_role_result.credentials;
+
+    let expiry = chrono::DateTime::parse_from_rfc3339(&credentials.expiration)
+        .map_err(|_| {
+            polars_err!(
+                ComputeError: "invalid STS AssumeRole expiration '{}'", credentials.expiration
+            )
+        })?
+        .timestamp();
+
+    Ok(AssumedRole {
+        credential: AwsCredential {
+            key_id: credentials.access_key_id,
+            secret_key: credentials.secret_access_key,
+            token: Some(credentials.session_token),
+        },
+        expiry: expiry.max(0) as u64,
+    })
+}
+
+/// Call STS `AssumeRole` for `role_arn`, signing the request with the source credentials.
+pub(super) async fn assume_role(
+    role_arn: &str,
+    external_id: Option<&str>,
+    session_name: &str,
+) -> PolarsResult<AssumedRole> {
+    let source = source_credential()?;
+
+    let region = std::env::var("AWS_REGION")
+        .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+        .ok()
+        .filter(|v| !v.is_empty());
+    // The global endpoint is signed for `us-east-1`.
+    let (host, region) = match region {
+        Some(region) => (format!("sts.{region}.amazonaws.com"), region),
+        None => ("sts.amazonaws.com".to_string(), "us-east-1".to_string()),
+    };
+
+    // The serializer is not `Sync`, so it must not be held across the await below.
+    let body = {
+        let mut body = url::form_urlencoded::Serializer::new(String::new());
+        body.append_pair("Action", "AssumeRole")
+            .append_pair("Version", STS_VERSION)
+            .append_pair("RoleArn", role_arn)
+            .append_pair("RoleSessionName", session_name);
+        if let Some(external_id) = external_id {
+            body.append_pair("ExternalId", external_id);
+        }
+        body.finish()
+    };
+
+    let url = format!("https://{host}/");
+    let mut request = HttpRequest::new(body.clone().into());
+    *request.method_mut() = Method::POST;
+    *request.uri_mut() = url.parse().map_err(to_compute_err)?;
+    request.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/x-www-form-urlencoded; charset=utf-8"),
+    );
+    AwsAuthorizer::new(&source, "sts", &region).authorize(&mut request, None);
+
+    let request = reqwest::Client::new()
+        .post(url)
+        .headers(request.headers().clone())
+        .body(body);
+
+    let response = request.send().await.map_err(to_compute_err)?;
+    let status = response.status();
+    let text = response.text().await.map_err(to_compute_err)?;
+    polars_ensure!(
+        status.is_success(),
+        ComputeError: "STS AssumeRole for '{}' failed with status {}: {}",
+        role_arn,
+        status,
+        quick_xml::de::from_str::<ErrorResponse>(&text).map_or(text, |e| e.error.message)
+    );
+    parse_response(&text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let xml = r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>ASIAEXAMPLE</AccessKeyId>
+      <SecretAccessKey>secret</SecretAccessKey>
+      <SessionToken>token</SessionToken>
+      <Expiration>2024-01-01T01:00:00Z</Expiration>
+    </Credentials>
+  </AssumeRoleResult>
+</AssumeRoleResponse>"#;
+
+        let role = parse_response(xml).unwrap();
+        assert_eq!(role.credential.key_id, "ASIAEXAMPLE");
+        assert_eq!(role.credential.secret_key, "secret");
+        assert_eq!(role.credential.token.as_deref(), Some("token"));
+        assert_eq!(role.expiry, 1704070800);
+
+        assert!(parse_response("<Error></Error>").is_err());
+    }
+}
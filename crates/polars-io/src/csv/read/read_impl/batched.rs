@@ -1,10 +1,13 @@
 use std::collections::VecDeque;
 use std::ops::Deref;
+use std::sync::Arc;
 
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
 use polars_core::POOL;
-use polars_core::datatypes::Field;
+use polars_core::datatypes::{CompatLevel, Field, IDX_DTYPE};
 use polars_core::frame::DataFrame;
-use polars_core::schema::SchemaRef;
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_error::PolarsResult;
 use polars_utils::IdxSize;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -261,6 +264,25 @@ impl BatchedCsvReader<'_> {
         }
         Ok(Some(chunks))
     }
+
+    /// The [`Schema`] of the [`DataFrame`]s returned by [`Self::next_batches`].
+    pub fn output_schema(&self) -> Schema {
+        let mut schema = self
+            .projection
+            .iter()
+            .map(|&i| self.schema.get_at_index(i).unwrap())
+            .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+            .collect::<Schema>();
+        for fld in &self.to_cast {
+            schema.set_dtype(&fld.name, fld.dtype.clone());
+        }
+        if let Some(rc) = &self.row_index {
+            schema
+                .insert_at_index(0, rc.name.clone(), IDX_DTYPE)
+                .unwrap();
+        }
+        schema
+    }
 }
 
 pub struct OwnedBatchedCsvReader {
@@ -268,6 +290,9 @@ pub struct OwnedBatchedCsvReader {
     // this exist because we need to keep ownership
     schema: SchemaRef,
     batched_reader: BatchedCsvReader<'static>,
+    // Used by the `RecordBatchReader` implementation.
+    arrow_schema: ArrowSchemaRef,
+    buffered: std::vec::IntoIter<DataFrame>,
     // keep ownership
     _reader: CsvReader<Box<dyn MmapBytesReader>>,
 }
@@ -278,11 +303,48 @@ impl OwnedBatchedCsvReader {
     }
 }
 
+impl Iterator for OwnedBatchedCsvReader {
+    type Item = PolarsResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(df) = self.buffered.next() {
+                let height = df.height();
+                let arrays = df
+                    .rechunk_to_record_batch(CompatLevel::newest())
+                    .into_arrays();
+                return Some(RecordBatch::try_new(
+                    height,
+                    self.arrow_schema.clone(),
+                    arrays,
+                ));
+            }
+
+            match self.next_batches(POOL.current_num_threads()) {
+                Ok(Some(dfs)) => self.buffered = dfs.into_iter(),
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl RecordBatchReader for OwnedBatchedCsvReader {
+    fn schema(&self) -> ArrowSchemaRef {
+        self.arrow_schema.clone()
+    }
+}
+
 pub fn to_batched_owned(
     mut reader: CsvReader<Box<dyn MmapBytesReader>>,
 ) -> PolarsResult<OwnedBatchedCsvReader> {
     let batched_reader = reader.batched_borrowed()?;
     let schema = batched_reader.schema.clone();
+    let arrow_schema = Arc::new(
+        batched_reader
+            .output_schema()
+            .to_arrow(CompatLevel::newest()),
+    );
     // If you put a drop(reader) here, rust will complain that reader is borrowed,
     // so we presumably have to keep ownership of it to maintain the safety of the
     // 'static transmute.
@@ -291,6 +353,8 @@ pub fn to_batched_owned(
     Ok(OwnedBatchedCsvReader {
         schema,
         batched_reader,
+        arrow_schema,
+        buffered: Vec::new().into_iter(),
         _reader: reader,
     })
 }
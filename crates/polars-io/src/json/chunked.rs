@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::num::NonZeroUsize;
+
+use polars_core::prelude::*;
+
+use crate::ndjson::core::parse_ndjson;
+use crate::ndjson::infer_schema;
+
+fn is_blank(line: &[u8]) -> bool {
+    line.iter()
+        .all(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+}
+
+/// Reads newline-delimited JSON in chunks of a fixed number of lines.
+///
+/// Unlike [`JsonReader`](super::JsonReader), the input isn't read into memory at once: every
+/// call to [`next_chunk`](Self::next_chunk) reads and parses only the lines of that chunk.
+/// Blank lines are skipped and don't count towards the chunk size.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::io::Cursor;
+///
+/// let data = "{\"a\": 1}\n{\"a\": 2}\n{\"a\": 3}\n";
+/// let mut reader = JsonlChunkReader::new(Cursor::new(data), None, 2);
+/// while let Some(df) = reader.next_chunk()? {
+///     println!("{df}");
+/// }
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct JsonlChunkReader<R: Read> {
+    reader: BufReader<R>,
+    schema: Option<SchemaRef>,
+    chunk_size: usize,
+    infer_schema_len: Option<NonZeroUsize>,
+    ignore_errors: bool,
+    /// Lines read for schema inference that haven't been emitted yet.
+    pending: VecDeque<Vec<u8>>,
+}
+
+impl<R: Read> JsonlChunkReader<R> {
+    /// Create a new reader that emits [`DataFrame`]s of `chunk_size` rows.
+    ///
+    /// If no `schema` is given, it is inferred from the first lines of the input when the first
+    /// chunk is read.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    pub fn new(reader: R, schema: Option<ArrowSchema>, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "`chunk_size` must be greater than zero");
+        Self {
+            reader: BufReader::new(reader),
+            schema: schema.map(|schema| Arc::new(Schema::from_arrow_schema(&schema))),
+            chunk_size,
+            infer_schema_len: Some(NonZeroUsize::new(100).unwrap()),
+            ignore_errors: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Set the number of lines used to infer the schema. `None` reads the whole input, after
+    /// which only the lines are kept in memory until they are emitted.
+    pub fn infer_schema_len(mut self, infer_schema_len: Option<NonZeroUsize>) -> Self {
+        self.infer_schema_len = infer_schema_len;
+        self
+    }
+
+    /// Set values to null instead of raising an error if they can't be parsed to the schema.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// The schema of the chunks, if it is known or has been inferred.
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.schema.as_ref()
+    }
+
+    /// Append the next non-blank line to `buf`, returning `false` at the end of the input.
+    fn read_line(&mut self, buf: &mut Vec<u8>) -> PolarsResult<bool> {
+        loop {
+            let start = buf.len();
+            if self.reader.read_until(b'\n', buf)? == 0 {
+                return Ok(false);
+            }
+            if is_blank(&buf[start..]) {
+                buf.truncate(start);
+            } else {
+                if buf.last() != Some(&b'\n') {
+                    buf.push(b'\n');
+                }
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Infer the schema from the first lines, returning `None` if the input is empty.
+    fn infer_schema(&mut self) -> PolarsResult<Option<SchemaRef>> {
+        let n = self.infer_schema_len.map_or(usize::MAX, |n| n.get());
+        let mut sample = Vec::new();
+        while self.pending.len() < n {
+            let mut line = Vec::new();
+            if !self.read_line(&mut line)? {
+                break;
+            }
+            sample.extend_from_slice(&line);
+            self.pending.push_back(line);
+        }
+        if sample.is_empty() {
+            return Ok(None);
+        }
+
+        let schema = Arc::new(infer_schema(&mut sample.as_slice(), self.infer_schema_len)?);
+        self.schema = Some(schema.clone());
+        Ok(Some(schema))
+    }
+
+    /// Read and parse the next `chunk_size` lines, returning `None` at the end of the input.
+    ///
+    /// The last chunk may contain less rows.
+    pub fn next_chunk(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let schema = match &self.schema {
+            Some(schema) => schema.clone(),
+            None => match self.infer_schema()? {
+                Some(schema) => schema,
+                None => return Ok(None),
+            },
+        };
+
+        let mut buf = Vec::new();
+        let mut n_lines = 0;
+        while n_lines < self.chunk_size {
+            match self.pending.pop_front() {
+                Some(line) => buf.extend_from_slice(&line),
+                None => {
+                    if !self.read_line(&mut buf)? {
+                        break;
+                    }
+                },
+            }
+            n_lines += 1;
+        }
+
+        if n_lines == 0 {
+            return Ok(None);
+        }
+        parse_ndjson(&buf, Some(n_lines), &schema, self.ignore_errors).map(Some)
+    }
+}
@@ -62,6 +62,7 @@
 //! +-----+--------+-------+--------+
 //! ```
 //!
+mod chunked;
 pub(crate) mod infer;
 
 use std::io::Write;
@@ -70,6 +71,7 @@ use std::ops::Deref;
 
 use arrow::array::LIST_VALUES_NAME;
 use arrow::legacy::conversion::chunk_to_struct;
+pub use chunked::JsonlChunkReader;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_error::{PolarsResult, polars_bail};
@@ -18,6 +18,7 @@
 mod async_impl;
 mod mmap;
 mod options;
+mod partitioned;
 mod read_impl;
 mod reader;
 mod utils;
@@ -32,10 +33,11 @@ or set 'streaming'",
 #[cfg(feature = "cloud")]
 pub use async_impl::ParquetObjectStore;
 pub use options::{ParallelStrategy, ParquetOptions};
+pub use partitioned::HivePartitionedParquetReader;
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
-pub use read_impl::{create_sorting_map, try_set_sorted_flag};
+pub use read_impl::{BatchedParquetReader, create_sorting_map, try_set_sorted_flag};
 pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
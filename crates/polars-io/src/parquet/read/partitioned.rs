@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+use std::fs::File;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::plpath::PlPath;
+
+use super::ParquetReader;
+use crate::cloud::CloudOptions;
+use crate::path_utils::expand_paths_hive;
+use crate::prelude::SerReader;
+
+/// Value that Hive uses for a null partition key.
+const HIVE_NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Reads all Parquet files below a Hive-partitioned directory (e.g.
+/// `s3://bucket/data/year=2024/month=01/0.parquet`) as a single [`DataFrame`].
+///
+/// The partition keys in `hive_schema` are parsed from the `key=value` directory names of each
+/// file and appended as columns, in the order of the schema.
+pub struct HivePartitionedParquetReader {
+    paths: Arc<[PlPath]>,
+    #[cfg_attr(not(feature = "cloud"), allow(dead_code))]
+    cloud_options: Option<CloudOptions>,
+    hive_schema: SchemaRef,
+}
+
+impl HivePartitionedParquetReader {
+    /// List all files below `base_url`, which may be a local path or a cloud URL and may contain
+    /// globs.
+    pub fn new(
+        base_url: &str,
+        cloud_options: Option<CloudOptions>,
+        hive_schema: Schema,
+    ) -> PolarsResult<Self> {
+        let (paths, _) =
+            expand_paths_hive(&[PlPath::new(base_url)], true, cloud_options.as_ref(), true)?;
+        polars_ensure!(
+            !paths.is_empty(),
+            ComputeError: "no Parquet files found at '{}'", base_url
+        );
+
+        Ok(Self {
+            paths,
+            cloud_options,
+            hive_schema: Arc::new(hive_schema),
+        })
+    }
+
+    /// The files that will be read.
+    pub fn paths(&self) -> &[PlPath] {
+        &self.paths
+    }
+
+    /// Read every file, add its partition columns and concatenate the results.
+    pub fn collect(&mut self) -> PolarsResult<DataFrame> {
+        let dfs = self
+            .paths
+            .iter()
+            .map(|path| {
+                let mut df = self.read_file(path)?;
+                let partitions = self.partition_columns(path, df.height())?;
+                df.hstack_mut(&partitions)?;
+                Ok(df)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let schema = dfs[0].schema();
+        for df in &dfs[1..] {
+            polars_ensure!(
+                df.schema() == schema,
+                SchemaMismatch: "all partition files must have the same schema, expected {:?}, got {:?}",
+                schema, df.schema()
+            );
+        }
+
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn read_file(&self, path: &PlPath) -> PolarsResult<DataFrame> {
+        if let Some(local_path) = path.as_ref().as_local_path() {
+            let file = polars_utils::open_file(local_path)?;
+            return ParquetReader::<File>::new(file).finish();
+        }
+
+        #[cfg(feature = "cloud")]
+        {
+            use crate::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+            let bytes = crate::pl_async::get_runtime().block_in_place_on(async {
+                let (CloudLocation { prefix, .. }, store) =
+                    build_object_store(path.to_str(), self.cloud_options.as_ref(), false).await?;
+                let object_path = object_path_from_str(&prefix)?;
+                let length = store.head(&object_path).await?.size as usize;
+                store.get_range(&object_path, 0..length).await
+            })?;
+            ParquetReader::new(std::io::Cursor::new(bytes)).finish()
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            polars_bail!(
+                ComputeError: "reading '{}' requires the 'cloud' feature", path.display()
+            )
+        }
+    }
+
+    /// Build a column of length `height` for every key of the Hive schema from the directory
+    /// names of `path`.
+    fn partition_columns(&self, path: &PlPath, height: usize) -> PolarsResult<Vec<Column>> {
+        let path_str = path.to_str();
+        // The last segment is the file name, which never holds a partition.
+        let segments = path_str
+            .split(['/', std::path::MAIN_SEPARATOR])
+            .rev()
+            .skip(1);
+
+        self.hive_schema
+            .iter()
+            .map(|(name, dtype)| {
+                let value = segments
+                    .clone()
+                    .find_map(|segment| {
+                        let (key, value) = segment.split_once('=')?;
+                        (key == name.as_str()).then_some(value)
+                    })
+                    .ok_or_else(|| {
+                        polars_err!(
+                            ComputeError: "partition key '{}' not found in path '{}'", name, path_str
+                        )
+                    })?;
+
+                let value = if value == HIVE_NULL_PARTITION {
+                    None
+                } else {
+                    Some(
+                        percent_encoding::percent_decode_str(value)
+                            .decode_utf8()
+                            .unwrap_or(Cow::Borrowed(value)),
+                    )
+                };
+                let column = Column::new(name.clone(), [value.as_deref()]).strict_cast(dtype)?;
+                Ok(column.new_from_index(0, height))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::ParquetWriter;
+
+    #[test]
+    fn test_hive_partitioned_reader() -> PolarsResult<()> {
+        let base = std::env::temp_dir().join(format!(
+            "polars-hive-partitioned-reader-{}",
+            std::process::id()
+        ));
+        for (year, month, values) in [(2023, "12", [1, 2]), (2024, "01", [3, 4])] {
+            let dir = base
+                .join(format!("year={year}"))
+                .join(format!("month={month}"));
+            std::fs::create_dir_all(&dir)?;
+            let mut df = df!["value" => values]?;
+            let file = File::create(dir.join("0.parquet"))?;
+            ParquetWriter::new(file).finish(&mut df)?;
+        }
+
+        let hive_schema = Schema::from_iter([
+            Field::new("year".into(), DataType::Int32),
+            Field::new("month".into(), DataType::String),
+        ]);
+        let mut reader =
+            HivePartitionedParquetReader::new(base.to_str().unwrap(), None, hive_schema)?;
+        assert_eq!(reader.paths().len(), 2);
+        let out = reader.collect()?.sort(["value"], Default::default())?;
+        std::fs::remove_dir_all(&base)?;
+
+        let expected = df![
+            "value" => [1, 2, 3, 4],
+            "year" => [2023, 2023, 2024, 2024],
+            "month" => ["12", "12", "01", "01"]
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowSchemaRef;
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
 use polars_core::chunked_array::builder::NullChunkedBuilder;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -530,3 +531,154 @@ impl PrefilterMaskSetting {
         }
     }
 }
+
+/// Reads a Parquet file one row group at a time, see [`ParquetReader::batched`].
+///
+/// [`ParquetReader::batched`]: super::ParquetReader::batched
+pub struct BatchedParquetReader<R> {
+    store: mmap::ColumnStore,
+    metadata: FileMetadataRef,
+    reader_schema: ArrowSchemaRef,
+    arrow_schema: ArrowSchemaRef,
+    projection: Vec<usize>,
+    slice: (usize, usize),
+    row_index: Option<RowIndex>,
+    hive_partition_columns: Option<Vec<Series>>,
+    include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    parallel: ParallelStrategy,
+    row_group_idx: usize,
+    rows_read: IdxSize,
+    // keep ownership of the bytes referenced by `store`. The reader is boxed so that bytes held
+    // inline by it (e.g. a `Cursor<[u8; N]>`) keep a stable address when `Self` is moved.
+    _reader: Box<R>,
+}
+
+impl<R: MmapBytesReader> BatchedParquetReader<R> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        reader: R,
+        metadata: FileMetadataRef,
+        reader_schema: ArrowSchemaRef,
+        projection: Option<Vec<usize>>,
+        slice: (usize, usize),
+        row_index: Option<RowIndex>,
+        hive_partition_columns: Option<Vec<Series>>,
+        include_file_path: Option<(PlSmallStr, Arc<str>)>,
+        parallel: ParallelStrategy,
+    ) -> Self {
+        let projection = projection.unwrap_or_else(|| (0usize..reader_schema.len()).collect());
+
+        let mut df = materialize_empty_df(
+            Some(&projection),
+            &reader_schema,
+            hive_partition_columns.as_deref(),
+            row_index.as_ref(),
+        );
+        if let Some((col, _)) = &include_file_path {
+            unsafe { df.with_column_unchecked(Column::new_empty(col.clone(), &DataType::String)) };
+        }
+        let arrow_schema = Arc::new(df.schema().to_arrow(CompatLevel::newest()));
+
+        // Row groups are read one at a time, so we can at most parallelize over the columns.
+        let parallel = match parallel {
+            ParallelStrategy::None => ParallelStrategy::None,
+            _ if projection.len() == 1 => ParallelStrategy::None,
+            _ => ParallelStrategy::Columns,
+        };
+
+        let mut reader = Box::new(reader);
+        let bytes = ReaderBytes::from(&mut *reader);
+        let store = mmap::ColumnStore::Local(unsafe {
+            std::mem::transmute::<ReaderBytes<'_>, ReaderBytes<'static>>(bytes).to_memslice()
+        });
+
+        Self {
+            store,
+            metadata,
+            reader_schema,
+            arrow_schema,
+            projection,
+            slice,
+            row_index,
+            hive_partition_columns,
+            include_file_path,
+            parallel,
+            row_group_idx: 0,
+            rows_read: 0,
+            _reader: reader,
+        }
+    }
+
+    /// Reads the next row group that overlaps with the slice.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let slice_end = self.slice.0.saturating_add(self.slice.1);
+
+        while self.row_group_idx < self.metadata.row_groups.len()
+            && (self.rows_read as usize) < slice_end
+        {
+            let rg_idx = self.row_group_idx;
+            self.row_group_idx += 1;
+
+            let dfs = rg_to_dfs_optionally_par_over_columns(
+                &self.store,
+                &mut self.rows_read,
+                rg_idx,
+                rg_idx + 1,
+                self.slice,
+                &self.metadata,
+                &self.reader_schema,
+                self.row_index.clone(),
+                self.parallel,
+                &self.projection,
+                self.hive_partition_columns.as_deref(),
+            )?;
+
+            for mut df in dfs {
+                if df.height() == 0 {
+                    continue;
+                }
+                if let Some((col, value)) = &self.include_file_path {
+                    unsafe {
+                        df.with_column_unchecked(Column::new_scalar(
+                            col.clone(),
+                            Scalar::new(
+                                DataType::String,
+                                AnyValue::StringOwned(value.as_ref().into()),
+                            ),
+                            df.height(),
+                        ))
+                    };
+                }
+                return Ok(Some(df));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<R: MmapBytesReader> Iterator for BatchedParquetReader<R> {
+    type Item = PolarsResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let df = match self.next_batch() {
+            Ok(df) => df?,
+            Err(e) => return Some(Err(e)),
+        };
+        let height = df.height();
+        let arrays = df
+            .rechunk_to_record_batch(CompatLevel::newest())
+            .into_arrays();
+        Some(RecordBatch::try_new(
+            height,
+            self.arrow_schema.clone(),
+            arrays,
+        ))
+    }
+}
+
+impl<R: MmapBytesReader> RecordBatchReader for BatchedParquetReader<R> {
+    fn schema(&self) -> ArrowSchemaRef {
+        self.arrow_schema.clone()
+    }
+}
@@ -5,7 +5,7 @@ use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 use polars_parquet::read;
 
-use super::read_impl::read_parquet;
+use super::read_impl::{BatchedParquetReader, read_parquet};
 use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
@@ -173,6 +173,28 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         }
         Ok(self.metadata.as_ref().unwrap())
     }
+
+    /// Read the file one row group at a time.
+    pub fn batched(mut self) -> PolarsResult<BatchedParquetReader<R>> {
+        let schema = self.schema()?;
+        let metadata = self.get_metadata()?.clone();
+
+        if let Some(cols) = &self.columns {
+            self.projection = Some(columns_to_projection(cols, schema.as_ref())?);
+        }
+
+        Ok(BatchedParquetReader::new(
+            self.reader,
+            metadata,
+            schema,
+            self.projection,
+            self.slice,
+            self.row_index,
+            self.hive_partition_columns,
+            self.include_file_path,
+            self.parallel,
+        ))
+    }
 }
 
 impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
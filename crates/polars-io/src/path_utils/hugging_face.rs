@@ -70,7 +70,9 @@ impl HFRepoLocation {
 impl HFPathParts {
     /// Extracts path components from a hugging face path:
     /// `hf:// [datasets | spaces] / {username} / {reponame} @ {revision} / {path from root}`
-    fn try_from_uri(uri: &str) -> PolarsResult<Self> {
+    ///
+    /// `default_revision` is used if the path does not contain a revision.
+    fn try_from_uri(uri: &str, default_revision: &str) -> PolarsResult<Self> {
         let Some(this) = (|| {
             // hf:// [datasets | spaces] / {username} / {reponame} @ {revision} / {path from root}
             //       !>
@@ -104,8 +106,8 @@ impl HFPathParts {
                 if let Some(i) = memchr::memchr(b'@', repository.as_bytes()) {
                     (repository[..i].to_string(), repository[1 + i..].to_string())
                 } else {
-                    // No @revision in uri, default to `main`
-                    (repository.to_string(), "main".to_string())
+                    // No @revision in uri, use the default
+                    (repository.to_string(), default_revision.to_string())
                 };
 
             // {path from root}
@@ -222,16 +224,19 @@ pub(super) async fn expand_paths_hf(
 
     let client = reqwest::ClientBuilder::new().http1_only().https_only(true);
 
-    let client = if let Some(CloudOptions {
-        config: Some(CloudConfig::Http { headers }),
+    let (client, default_revision) = if let Some(CloudOptions {
+        config: Some(CloudConfig::Http { headers, revision }),
         ..
     }) = cloud_options
     {
-        client.default_headers(try_build_http_header_map_from_items_slice(
-            headers.as_slice(),
-        )?)
+        (
+            client.default_headers(try_build_http_header_map_from_items_slice(
+                headers.as_slice(),
+            )?),
+            revision.as_deref().unwrap_or("main"),
+        )
     } else {
-        client
+        (client, "main")
     };
 
     let client = &client.build().unwrap();
@@ -246,7 +251,7 @@ pub(super) async fn expand_paths_hf(
     };
 
     for (path_idx, path) in paths.iter().enumerate() {
-        let path_parts = &HFPathParts::try_from_uri(path.to_str())?;
+        let path_parts = &HFPathParts::try_from_uri(path.to_str(), default_revision)?;
         let repo_location = &HFRepoLocation::new(
             &path_parts.bucket,
             &path_parts.repository,
@@ -347,7 +352,7 @@ mod tests {
             path: "README.md".into(),
         };
 
-        assert_eq!(HFPathParts::try_from_uri(uri).unwrap(), expect);
+        assert_eq!(HFPathParts::try_from_uri(uri, "main").unwrap(), expect);
 
         let uri = "hf://spaces/pola-rs/polars@~parquet/";
         let expect = HFPathParts {
@@ -357,7 +362,7 @@ mod tests {
             path: "".into(),
         };
 
-        assert_eq!(HFPathParts::try_from_uri(uri).unwrap(), expect);
+        assert_eq!(HFPathParts::try_from_uri(uri, "main").unwrap(), expect);
 
         let uri = "hf://spaces/pola-rs/polars@~parquet";
         let expect = HFPathParts {
@@ -367,7 +372,27 @@ mod tests {
             path: "".into(),
         };
 
-        assert_eq!(HFPathParts::try_from_uri(uri).unwrap(), expect);
+        assert_eq!(HFPathParts::try_from_uri(uri, "main").unwrap(), expect);
+
+        let uri = "hf://datasets/pola-rs/polars@sha256:abc/data/file.parquet";
+        let expect = HFPathParts {
+            bucket: "datasets".into(),
+            repository: "pola-rs/polars".into(),
+            revision: "sha256:abc".into(),
+            path: "data/file.parquet".into(),
+        };
+
+        assert_eq!(HFPathParts::try_from_uri(uri, "main").unwrap(), expect);
+
+        let uri = "hf://datasets/pola-rs/polars/data/file.parquet";
+        let expect = HFPathParts {
+            bucket: "datasets".into(),
+            repository: "pola-rs/polars".into(),
+            revision: "v1.0".into(),
+            path: "data/file.parquet".into(),
+        };
+
+        assert_eq!(HFPathParts::try_from_uri(uri, "v1.0").unwrap(), expect);
 
         for uri in [
             "://",
@@ -380,7 +405,7 @@ mod tests {
             "hf://datasets/a/",
             "hf://bucket/a/b/c", // Invalid bucket name
         ] {
-            let out = HFPathParts::try_from_uri(uri);
+            let out = HFPathParts::try_from_uri(uri, "main");
             if out.is_err() {
                 continue;
             }
@@ -1,7 +1,7 @@
 use std::io::{Read, Write};
 use std::sync::Arc;
 
-use arrow::array::new_empty_array;
+use arrow::array::{OwnedArray, new_empty_array};
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 use polars_utils::plpath::PlPathRef;
@@ -109,7 +109,8 @@ pub(crate) fn finish_reader<R: ArrowReader>(
             let empty_cols = arrow_schema
                 .iter_values()
                 .map(|fld| {
-                    Series::try_from((fld.name.clone(), new_empty_array(fld.dtype.clone())))
+                    OwnedArray::new(fld.name.clone(), new_empty_array(fld.dtype.clone()))
+                        .to_series(arrow_schema)
                         .map(Column::from)
                 })
                 .collect::<PolarsResult<_>>()?;
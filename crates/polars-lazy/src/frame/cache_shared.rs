@@ -0,0 +1,417 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::Arc;
+
+use polars_utils::aliases::{PlFixedStateQuality, PlHashMap, PlHashSet};
+
+use super::LazyFrame;
+use crate::prelude::DslPlan;
+
+/// Split the inputs of a plan into the ones behind an `Arc` (which can be shared between plans)
+/// and the ones that are owned by the node. Works on both `&DslPlan` and `&mut DslPlan`.
+macro_rules! plan_inputs {
+    ($plan:expr) => {{
+        let mut shared = Vec::new();
+        let mut owned = Vec::new();
+        match $plan {
+            DslPlan::Filter { input, .. }
+            | DslPlan::Cache { input }
+            | DslPlan::Select { input, .. }
+            | DslPlan::GroupBy { input, .. }
+            | DslPlan::HStack { input, .. }
+            | DslPlan::MatchToSchema { input, .. }
+            | DslPlan::Distinct { input, .. }
+            | DslPlan::Sort { input, .. }
+            | DslPlan::Slice { input, .. }
+            | DslPlan::MapFunction { input, .. }
+            | DslPlan::Sink { input, .. } => shared.push(input),
+            DslPlan::Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                shared.push(input_left);
+                shared.push(input_right);
+            },
+            #[cfg(feature = "merge_sorted")]
+            DslPlan::MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                shared.push(input_left);
+                shared.push(input_right);
+            },
+            DslPlan::ExtContext { input, contexts } => {
+                shared.push(input);
+                owned.extend(contexts);
+            },
+            DslPlan::Union { inputs, .. }
+            | DslPlan::HConcat { inputs, .. }
+            | DslPlan::SinkMultiple { inputs } => owned.extend(inputs),
+            // Sources have no inputs, and the plan of an `IR` node must stay in sync with its
+            // converted node.
+            _ => {},
+        }
+        (shared, owned)
+    }};
+}
+
+fn collect_shared<'a>(
+    plan: &'a DslPlan,
+    ptrs: &mut PlHashSet<usize>,
+    nodes: &mut Vec<&'a Arc<DslPlan>>,
+) {
+    let (shared, owned) = plan_inputs!(plan);
+    for input in shared {
+        if ptrs.insert(Arc::as_ptr(input).addr()) {
+            nodes.push(input);
+            collect_shared(input, ptrs, nodes);
+        }
+    }
+    for input in owned {
+        collect_shared(input, ptrs, nodes);
+    }
+}
+
+/// Structural hashes of the nodes of one or more plans, memoised by node address.
+///
+/// Nodes whose arguments can't be compared (sources other than in-memory frames, UDFs, sinks
+/// and already converted plans) are hashed and compared by identity.
+struct PlanHasher {
+    hb: PlFixedStateQuality,
+    hashes: PlHashMap<usize, u64>,
+}
+
+impl PlanHasher {
+    fn new() -> Self {
+        Self {
+            hb: PlFixedStateQuality::with_seed(0),
+            hashes: PlHashMap::default(),
+        }
+    }
+
+    fn hash(&mut self, plan: &DslPlan) -> u64 {
+        let addr = (plan as *const DslPlan).addr();
+        if let Some(h) = self.hashes.get(&addr) {
+            return *h;
+        }
+
+        let mut state = self.hb.build_hasher();
+        std::mem::discriminant(plan).hash(&mut state);
+        if is_comparable(plan) {
+            hash_args(plan, &mut state);
+            let (shared, owned) = plan_inputs!(plan);
+            for input in shared {
+                self.hash(input).hash(&mut state);
+            }
+            for input in owned {
+                self.hash(input).hash(&mut state);
+            }
+        } else {
+            addr.hash(&mut state);
+        }
+        let h = state.finish();
+        self.hashes.insert(addr, h);
+        h
+    }
+
+    /// Whether `a` and `b` compute the same result.
+    fn plans_eq(&mut self, a: &DslPlan, b: &DslPlan) -> bool {
+        if std::ptr::eq(a, b) {
+            return true;
+        }
+        if !is_comparable(a) || self.hash(a) != self.hash(b) || !args_eq(a, b) {
+            return false;
+        }
+        let (shared_a, owned_a) = plan_inputs!(a);
+        let (shared_b, owned_b) = plan_inputs!(b);
+        shared_a.len() == shared_b.len()
+            && owned_a.len() == owned_b.len()
+            && shared_a
+                .into_iter()
+                .zip(shared_b)
+                .all(|(a, b)| self.plans_eq(a, b))
+            && owned_a
+                .into_iter()
+                .zip(owned_b)
+                .all(|(a, b)| self.plans_eq(a, b))
+    }
+}
+
+fn is_comparable(plan: &DslPlan) -> bool {
+    match plan {
+        DslPlan::GroupBy { apply, .. } => apply.is_none(),
+        DslPlan::Filter { .. }
+        | DslPlan::DataFrameScan { .. }
+        | DslPlan::Select { .. }
+        | DslPlan::Join { .. }
+        | DslPlan::HStack { .. }
+        | DslPlan::Distinct { .. }
+        | DslPlan::Sort { .. }
+        | DslPlan::Slice { .. }
+        | DslPlan::Union { .. }
+        | DslPlan::HConcat { .. } => true,
+        _ => false,
+    }
+}
+
+/// Hash the arguments of a comparable node, not its inputs.
+fn hash_args<H: Hasher>(plan: &DslPlan, state: &mut H) {
+    use DslPlan as D;
+    match plan {
+        D::Filter { predicate, .. } => predicate.hash(state),
+        D::DataFrameScan { df, schema } => {
+            schema.hash(state);
+            df.height().hash(state);
+        },
+        D::Select { expr, options, .. } => {
+            expr.hash(state);
+            options.hash(state);
+        },
+        D::GroupBy {
+            keys,
+            aggs,
+            maintain_order,
+            options,
+            ..
+        } => {
+            keys.hash(state);
+            aggs.hash(state);
+            maintain_order.hash(state);
+            options.hash(state);
+        },
+        D::Join {
+            left_on,
+            right_on,
+            predicates,
+            options,
+            ..
+        } => {
+            left_on.hash(state);
+            right_on.hash(state);
+            predicates.hash(state);
+            options.hash(state);
+        },
+        D::HStack { exprs, options, .. } => {
+            exprs.hash(state);
+            options.hash(state);
+        },
+        D::Distinct { options, .. } => options.hash(state),
+        D::Sort {
+            by_column,
+            slice,
+            sort_options,
+            ..
+        } => {
+            by_column.hash(state);
+            slice.hash(state);
+            sort_options.hash(state);
+        },
+        D::Slice { offset, len, .. } => {
+            offset.hash(state);
+            len.hash(state);
+        },
+        D::Union { args, .. } => args.hash(state),
+        D::HConcat { options, .. } => options.hash(state),
+        // Nodes that aren't comparable are identified by their address, as in `PlanHasher::hash`.
+        _ => (plan as *const DslPlan).addr().hash(state),
+    }
+}
+
+/// Whether the arguments of two comparable nodes are equal, not their inputs.
+fn args_eq(a: &DslPlan, b: &DslPlan) -> bool {
+    use DslPlan as D;
+    match (a, b) {
+        (D::Filter { predicate: l, .. }, D::Filter { predicate: r, .. }) => l == r,
+        (
+            D::DataFrameScan {
+                df: dfl,
+                schema: sl,
+            },
+            D::DataFrameScan {
+                df: dfr,
+                schema: sr,
+            },
+        ) => sl == sr && (Arc::ptr_eq(dfl, dfr) || dfl.equals_missing(dfr)),
+        (
+            D::Select {
+                expr: el,
+                options: ol,
+                ..
+            },
+            D::Select {
+                expr: er,
+                options: or,
+                ..
+            },
+        )
+        | (
+            D::HStack {
+                exprs: el,
+                options: ol,
+                ..
+            },
+            D::HStack {
+                exprs: er,
+                options: or,
+                ..
+            },
+        ) => el == er && ol == or,
+        (
+            D::GroupBy {
+                keys: kl,
+                aggs: al,
+                maintain_order: ml,
+                options: ol,
+                ..
+            },
+            D::GroupBy {
+                keys: kr,
+                aggs: ar,
+                maintain_order: mr,
+                options: or,
+                ..
+            },
+        ) => kl == kr && al == ar && ml == mr && ol == or,
+        (
+            D::Join {
+                left_on: ll,
+                right_on: rl,
+                predicates: pl,
+                options: ol,
+                ..
+            },
+            D::Join {
+                left_on: lr,
+                right_on: rr,
+                predicates: pr,
+                options: or,
+                ..
+            },
+        ) => ll == lr && rl == rr && pl == pr && ol == or,
+        (D::Distinct { options: l, .. }, D::Distinct { options: r, .. }) => l == r,
+        (
+            D::Sort {
+                by_column: bl,
+                slice: sl,
+                sort_options: ol,
+                ..
+            },
+            D::Sort {
+                by_column: br,
+                slice: sr,
+                sort_options: or,
+                ..
+            },
+        ) => bl == br && sl == sr && ol == or,
+        (
+            D::Slice {
+                offset: ol,
+                len: ll,
+                ..
+            },
+            D::Slice {
+                offset: or,
+                len: lr,
+                ..
+            },
+        ) => ol == or && ll == lr,
+        (D::Union { args: l, .. }, D::Union { args: r, .. }) => l == r,
+        (D::HConcat { options: l, .. }, D::HConcat { options: r, .. }) => l == r,
+        _ => false,
+    }
+}
+
+/// Find the largest sub-plans of `plan` that also occur in the other plan and create a cache
+/// node for each of them.
+fn find_common_sub_plans(
+    plan: &DslPlan,
+    other_nodes: &PlHashMap<u64, Vec<&Arc<DslPlan>>>,
+    hasher: &mut PlanHasher,
+    caches: &mut PlHashMap<usize, Arc<DslPlan>>,
+) {
+    let (shared, owned) = plan_inputs!(plan);
+    for input in shared {
+        let other = other_nodes.get(&hasher.hash(input)).and_then(|candidates| {
+            candidates
+                .iter()
+                .find(|other| hasher.plans_eq(input, other))
+        });
+        match other {
+            // Cache nodes that are already shared will already resolve to the same cache.
+            Some(other)
+                if Arc::ptr_eq(input, other) && matches!(input.as_ref(), DslPlan::Cache { .. }) => {
+            },
+            Some(other) => {
+                let cache = caches
+                    .entry(Arc::as_ptr(other).addr())
+                    .or_insert_with(|| {
+                        Arc::new(DslPlan::Cache {
+                            input: input.clone(),
+                        })
+                    })
+                    .clone();
+                caches.insert(Arc::as_ptr(input).addr(), cache);
+            },
+            None => find_common_sub_plans(input, other_nodes, hasher, caches),
+        }
+    }
+    for input in owned {
+        find_common_sub_plans(input, other_nodes, hasher, caches);
+    }
+}
+
+fn insert_caches(plan: &mut DslPlan, caches: &PlHashMap<usize, Arc<DslPlan>>) {
+    let (shared, owned) = plan_inputs!(plan);
+    for input in shared {
+        match caches.get(&Arc::as_ptr(input).addr()) {
+            Some(cache) => *input = cache.clone(),
+            None => insert_caches(Arc::make_mut(input), caches),
+        }
+    }
+    for input in owned {
+        insert_caches(input, caches);
+    }
+}
+
+impl LazyFrame {
+    /// Let two independently built [`LazyFrame`]s share the cache of their common sub-plans.
+    ///
+    /// Sub-plans are common if they are equal in structure: they apply the same operations with
+    /// the same arguments to the same sources. This holds for frames derived from the same
+    /// [`LazyFrame`], but also for frames that were built independently. In-memory frames are
+    /// compared by content. Other sources, UDF nodes (e.g. [`LazyFrame::map`]) and sinks only
+    /// match the very same node. The largest common sub-plans get a [`LazyFrame::cache`] node
+    /// that is used by both returned frames, so that collecting them together (e.g. with
+    /// [`LazyFrame::collect_all_with_engine`]) computes those sub-plans only once.
+    ///
+    /// Frames without common sub-plans are returned unchanged.
+    pub fn cache_shared(lhs: LazyFrame, rhs: LazyFrame) -> (LazyFrame, LazyFrame) {
+        let caches = {
+            let mut lhs_ptrs = PlHashSet::default();
+            let mut lhs_nodes = Vec::new();
+            collect_shared(&lhs.logical_plan, &mut lhs_ptrs, &mut lhs_nodes);
+
+            let mut hasher = PlanHasher::new();
+            let mut by_hash = PlHashMap::<u64, Vec<&Arc<DslPlan>>>::default();
+            for node in lhs_nodes {
+                by_hash.entry(hasher.hash(node)).or_default().push(node);
+            }
+            let mut caches = PlHashMap::default();
+            find_common_sub_plans(&rhs.logical_plan, &by_hash, &mut hasher, &mut caches);
+            caches
+        };
+
+        if caches.is_empty() {
+            return (lhs, rhs);
+        }
+
+        let annotate = |lf: LazyFrame| {
+            let opt_state = lf.get_opt_state();
+            let mut plan = lf.logical_plan;
+            insert_caches(&mut plan, &caches);
+            Self::from_logical_plan(plan, opt_state)
+        };
+        (annotate(lhs), annotate(rhs))
+    }
+}
@@ -2,6 +2,7 @@
 #[cfg(feature = "python")]
 mod python;
 
+mod cache_shared;
 mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
@@ -41,6 +42,12 @@ use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterato
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
 
+#[cfg(feature = "ir_serde")]
+const OPTIMIZED_PLAN_MAGIC_BYTES: &[u8] = b"OPTIMIZED_PLAN_VERSION";
+// The IR format is not versioned separately, so plans are only compatible within a release.
+#[cfg(feature = "ir_serde")]
+const OPTIMIZED_PLAN_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -193,6 +200,15 @@ impl LazyFrame {
         self
     }
 
+    /// Eliminate redundant expression computations.
+    ///
+    /// Structurally equal sub-expressions within a single projection, `with_columns`,
+    /// aggregation or filter are computed once into a temporary column and reused.
+    #[cfg(feature = "cse")]
+    pub fn deduplicate_expressions(self) -> Self {
+        self.with_comm_subexpr_elim(true)
+    }
+
     /// Toggle slice pushdown optimization.
     pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::SLICE_PUSHDOWN, toggle);
@@ -335,6 +351,21 @@ impl LazyFrame {
         }
     }
 
+    /// Add a stable sort operation to the logical plan.
+    ///
+    /// Like [`LazyFrame::sort_by_exprs`], but rows that are equal under the sort keys always
+    /// keep their original relative order, regardless of `maintain_order` in `sort_options`.
+    ///
+    /// Stable sorting is more expensive than [`LazyFrame::sort_by_exprs`], so prefer the latter
+    /// if the order of tied rows doesn't matter.
+    pub fn sort_by_exprs_stable<E: AsRef<[Expr]>>(
+        self,
+        by_exprs: E,
+        sort_options: SortMultipleOptions,
+    ) -> Self {
+        self.sort_by_exprs(by_exprs, sort_options.with_maintain_order(true))
+    }
+
     pub fn top_k<E: AsRef<[Expr]>>(
         self,
         k: IdxSize,
@@ -500,6 +531,36 @@ impl LazyFrame {
         }])
     }
 
+    /// Override the schema of this LazyFrame.
+    ///
+    /// The columns are matched to the fields of `schema` by position; each column is renamed and
+    /// strictly cast to the corresponding field. This errors if the number of fields differs or
+    /// if a column can't be cast to the new dtype.
+    pub fn with_new_schema(mut self, schema: Schema) -> PolarsResult<LazyFrame> {
+        let current = self.collect_schema()?;
+        polars_ensure!(
+            current.len() == schema.len(),
+            SchemaMismatch: "expected {} fields in the new schema, got {}",
+            current.len(), schema.len()
+        );
+
+        let exprs = current
+            .iter()
+            .zip(schema.iter())
+            .map(|((name, dtype), (new_name, new_dtype))| {
+                polars_ensure!(
+                    dtype.can_cast_to(new_dtype) != Some(false),
+                    InvalidOperation: "cannot cast column '{}' from {} to {}",
+                    name, dtype, new_dtype
+                );
+                Ok(col(name.clone())
+                    .strict_cast(new_dtype.clone())
+                    .alias(new_name.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(self.select(exprs))
+    }
+
     pub fn optimize(
         self,
         lp_arena: &mut Arena<IR>,
@@ -527,6 +588,72 @@ impl LazyFrame {
         Ok(plan)
     }
 
+    /// Optimize the query and serialize the resulting [`IRPlan`].
+    ///
+    /// The bytes can be loaded with [`LazyFrame::from_optimized_bytes`], which allows
+    /// repeated execution of the same query to skip the optimization step. The IR has no stable
+    /// format, so they can only be loaded by the same Polars version.
+    #[cfg(feature = "ir_serde")]
+    pub fn optimize_and_serialize(&self) -> PolarsResult<Vec<u8>> {
+        let dsl = self.logical_plan.clone();
+        let plan = self.clone().to_alp_optimized()?;
+
+        let version = OPTIMIZED_PLAN_VERSION.as_bytes();
+        let mut bytes = Vec::with_capacity(OPTIMIZED_PLAN_MAGIC_BYTES.len() + 1 + version.len());
+        bytes.extend_from_slice(OPTIMIZED_PLAN_MAGIC_BYTES);
+        bytes.push(version.len() as u8);
+        bytes.extend_from_slice(version);
+        // Keep the DSL around so that the plan can be converted again if it is combined with
+        // another `LazyFrame` and the optimized arenas can't be reused.
+        polars_utils::pl_serialize::serialize_into_writer::<_, _, false>(&mut bytes, &(dsl, plan))?;
+        Ok(bytes)
+    }
+
+    /// Load a plan that was serialized with [`LazyFrame::optimize_and_serialize`].
+    ///
+    /// As the plan is already optimized, the returned [`LazyFrame`] has its optimizations
+    /// turned off. They can be turned on again with [`LazyFrame::with_optimizations`].
+    ///
+    /// # Errors
+    /// Errors if the plan was serialized by a different Polars version.
+    #[cfg(feature = "ir_serde")]
+    pub fn from_optimized_bytes(bytes: &[u8]) -> PolarsResult<LazyFrame> {
+        let Some(bytes) = bytes.strip_prefix(OPTIMIZED_PLAN_MAGIC_BYTES) else {
+            polars_bail!(ComputeError: "optimized plan magic bytes not found")
+        };
+        let Some((&version_len, bytes)) = bytes.split_first() else {
+            polars_bail!(ComputeError: "failed to read incoming optimized plan version")
+        };
+        polars_ensure!(
+            bytes.len() >= version_len as usize,
+            ComputeError: "failed to read incoming optimized plan version"
+        );
+        let (version, bytes) = bytes.split_at(version_len as usize);
+        polars_ensure!(
+            version == OPTIMIZED_PLAN_VERSION.as_bytes(),
+            ComputeError:
+            "deserialization failed\n\ngiven optimized plan version: {} is not compatible with this Polars version: {}",
+            String::from_utf8_lossy(version), OPTIMIZED_PLAN_VERSION
+        );
+
+        let (dsl, plan): (DslPlan, IRPlan) =
+            polars_utils::pl_serialize::deserialize_from_reader::<_, _, false>(bytes)?;
+        let IRPlan {
+            lp_top,
+            lp_arena,
+            expr_arena,
+        } = plan;
+
+        let logical_plan = DslPlan::IR {
+            node: Some(lp_top),
+            dsl: Arc::new(dsl),
+            version: lp_arena.version(),
+        };
+        let lf = LazyFrame::from(logical_plan).without_optimizations();
+        lf.set_cached_arena(lp_arena, expr_arena);
+        Ok(lf)
+    }
+
     pub(crate) fn optimize_with_scratch(
         self,
         lp_arena: &mut Arena<IR>,
@@ -1063,6 +1190,62 @@ impl LazyFrame {
         }))
     }
 
+    /// Run the query and write the result as newline-delimited JSON, partitioned by the values of
+    /// `partition_by`.
+    ///
+    /// Every partition is written to `{base_path}/{key}={value}/.../data.ndjson`. This is a
+    /// shorthand for [`LazyFrame::sink_json_partitioned`] with [`PartitionVariant::ByKey`].
+    #[cfg(feature = "json")]
+    pub fn sink_json_partitioned_by_key(
+        self,
+        base_path: &str,
+        partition_by: Vec<Expr>,
+        options: JsonWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            !partition_by.is_empty(),
+            InvalidOperation: "'sink_json_partitioned_by_key' requires at least one partition key"
+        );
+
+        // Replace the `{in_part_idx}.{ext}` file name of the default path.
+        let file_path_cb = PartitionTargetCallback::Rust(SpecialEq::new(Arc::new(
+            |ctx: PartitionTargetContext| {
+                let file_name = match ctx.in_part_idx {
+                    0 => "data.ndjson".to_string(),
+                    idx => format!("data_{idx}.ndjson"),
+                };
+                let path = match polars_utils::plpath::PlPathRef::new(&ctx.file_path).parent() {
+                    Some(dir) => dir.join(file_name),
+                    None => PlPath::new(&file_name),
+                };
+                Ok(PartitionTargetCallbackResult::Str(
+                    path.to_str().to_string(),
+                ))
+            },
+        )));
+
+        self.sink_json_partitioned(
+            Arc::new(PlPath::new(base_path)),
+            Some(file_path_cb),
+            PartitionVariant::ByKey {
+                key_exprs: partition_by,
+                include_key: true,
+            },
+            options,
+            cloud_options,
+            // Every partition is written to its own directory.
+            SinkOptions {
+                mkdir: true,
+                ..Default::default()
+            },
+            None,
+            None,
+        )?
+        .collect_with_engine(Engine::Auto)
+        .map(|_| ())
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -1562,6 +1745,201 @@ impl LazyFrame {
         self._join_impl(other, left_on, right_on, args)
     }
 
+    /// Join this [`LazyFrame`] with `other` on the rows for which all `predicates` hold.
+    ///
+    /// The join is planned as a cross join followed by a filter. Equality clauses in the
+    /// predicates are turned into the keys of a hash join and inequality clauses into an
+    /// inequality join by the optimizer, so the full cross product is only materialized if
+    /// needed. Only [`JoinType::Inner`] is supported, other join types give an error when the
+    /// plan is resolved. The `slice` in `args` is applied to the joined result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///     ldf.join_where(
+    ///         other,
+    ///         [col("id").eq(col("id_right")), col("start").lt_eq(col("time"))],
+    ///         JoinArgs::new(JoinType::Inner),
+    ///     )
+    /// }
+    /// ```
+    pub fn join_where<E: AsRef<[Expr]>>(
+        self,
+        other: LazyFrame,
+        predicates: E,
+        args: JoinArgs,
+    ) -> LazyFrame {
+        let JoinArgs {
+            how,
+            validation,
+            suffix,
+            slice,
+            nulls_equal,
+            coalesce,
+            maintain_order,
+        } = args;
+
+        let mut builder = self
+            .join_builder()
+            .with(other)
+            .how(how)
+            .validate(validation)
+            .join_nulls(nulls_equal)
+            .coalesce(coalesce)
+            .maintain_order(maintain_order);
+
+        if let Some(suffix) = suffix {
+            builder = builder.suffix(suffix);
+        }
+
+        let lf = builder.join_where(predicates.as_ref().to_vec());
+        match slice {
+            Some((offset, len)) => lf.slice(offset, len as IdxSize),
+            None => lf,
+        }
+    }
+
+    /// Keep the rows of this [`LazyFrame`] for which `predicate` holds for at least one row of
+    /// `other`.
+    ///
+    /// This is planned as a [`join_where`](LazyFrame::join_where), so equality clauses in the
+    /// predicate become the keys of a hash join and the full predicate is only checked for the
+    /// rows with matching keys. As in `join_where`, columns of `other` that also exist in this
+    /// frame are referred to with a `"_right"` suffix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///     ldf.semi_join_where(
+    ///         other,
+    ///         col("id").eq(col("id_right")).and(col("start").lt_eq(col("time"))),
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    pub fn semi_join_where(self, other: LazyFrame, predicate: Expr) -> LazyFrame {
+        self.filter_join_where(other, predicate, JoinType::Semi)
+    }
+
+    /// Keep the rows of this [`LazyFrame`] for which `predicate` doesn't hold for any row of
+    /// `other`.
+    ///
+    /// See [`semi_join_where`](LazyFrame::semi_join_where) for how the predicate is evaluated.
+    #[cfg(feature = "semi_anti_join")]
+    pub fn anti_join_where(self, other: LazyFrame, predicate: Expr) -> LazyFrame {
+        self.filter_join_where(other, predicate, JoinType::Anti)
+    }
+
+    #[cfg(feature = "semi_anti_join")]
+    fn filter_join_where(self, other: LazyFrame, predicate: Expr, how: JoinType) -> LazyFrame {
+        const ROW_INDEX: &str = "__POLARS_JOIN_WHERE_ROW_INDEX";
+
+        let lf = self.with_row_index(ROW_INDEX, None);
+        let matches = lf
+            .clone()
+            .join_where(other, [predicate], JoinArgs::new(JoinType::Inner))
+            .select([col(ROW_INDEX)]);
+        lf.join(
+            matches,
+            [col(ROW_INDEX)],
+            [col(ROW_INDEX)],
+            JoinArgs::new(how),
+        )
+        .drop(cols([ROW_INDEX]))
+    }
+
+    /// Perform an asof join on `on` within the groups of equal `by_left` and `by_right` keys.
+    ///
+    /// Every row of `self` is matched with the nearest row of `other` (according to `strategy`)
+    /// that has the same `by` keys. Both frames must be sorted by `on` within the groups. The
+    /// `by` expressions that are not plain columns are added to the respective frame under
+    /// their output name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// use polars_ops::frame::AsofStrategy;
+    ///
+    /// fn example(readings: LazyFrame, calibrations: LazyFrame) -> LazyFrame {
+    ///     readings.join_asof_by(
+    ///         calibrations,
+    ///         col("time"),
+    ///         vec![col("sensor_id")],
+    ///         vec![col("sensor_id")],
+    ///         AsofStrategy::Backward,
+    ///         None,
+    ///         None,
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "asof_join")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn join_asof_by(
+        self,
+        other: LazyFrame,
+        on: Expr,
+        by_left: Vec<Expr>,
+        by_right: Vec<Expr>,
+        strategy: polars_ops::frame::AsofStrategy,
+        tolerance: Option<AnyValue<'static>>,
+        suffix: Option<PlSmallStr>,
+    ) -> LazyFrame {
+        fn by_names(lf: LazyFrame, by: Vec<Expr>) -> (LazyFrame, Vec<PlSmallStr>) {
+            let mut names = Vec::with_capacity(by.len());
+            let mut exprs = Vec::new();
+            for e in by {
+                match e {
+                    Expr::Column(name) => names.push(name),
+                    e => {
+                        // An expression without an output name fails when the join is resolved.
+                        names.push(expr_output_name(&e).unwrap_or_default());
+                        exprs.push(e);
+                    },
+                }
+            }
+            let lf = if exprs.is_empty() {
+                lf
+            } else {
+                lf.with_columns(exprs)
+            };
+            (lf, names)
+        }
+
+        let (lf, left_by) = by_names(self, by_left);
+        let (other, right_by) = by_names(other, by_right);
+
+        let options = polars_ops::frame::AsOfOptions {
+            strategy,
+            tolerance: tolerance.map(|av| Scalar::new(av.dtype(), av)),
+            left_by: Some(left_by),
+            right_by: Some(right_by),
+            allow_eq: true,
+            check_sortedness: true,
+            ..Default::default()
+        };
+
+        let mut builder = lf
+            .join_builder()
+            .with(other)
+            .left_on([on.clone()])
+            .right_on([on])
+            .how(JoinType::AsOf(Box::new(options)));
+        if let Some(suffix) = suffix {
+            builder = builder.suffix(suffix);
+        }
+        builder.finish()
+    }
+
     fn _join_impl(
         self,
         other: LazyFrame,
@@ -1993,6 +2371,15 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Attach a `label` to the logical plan at this point of the query.
+    ///
+    /// The label is shown in the output of [`LazyFrame::explain`] and in profiling reports,
+    /// which makes it easier to correlate parts of a complex query. The labeled node passes the
+    /// data through unchanged and does not block predicate or projection pushdown.
+    pub fn with_plan_metadata(self, label: impl Into<String>) -> LazyFrame {
+        self.map_private(DslFunction::Label(PlSmallStr::from_string(label.into())))
+    }
+
     pub(crate) fn map_private(self, function: DslFunction) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().map_private(function).build();
@@ -566,3 +566,39 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+#[cfg(feature = "tdigest")]
+fn test_tdigest_merge_group_by() -> PolarsResult<()> {
+    use polars_utils::pl_serialize;
+    use tdigest::TDigest;
+
+    fn sketch(values: impl IntoIterator<Item = i32>) -> Vec<u8> {
+        let values = values.into_iter().map(|v| v as f64).collect();
+        let digest = TDigest::new_with_size(100).merge_unsorted(values);
+        pl_serialize::serialize_to_bytes::<_, false>(&digest).unwrap()
+    }
+
+    let (a, b, c) = (sketch(1..=500), sketch(501..=1000), sketch(1..=100));
+    let sketches = BinaryChunked::from_slice_options(
+        "sketch".into(),
+        &[Some(&a[..]), Some(&b[..]), None, Some(&c[..])],
+    );
+    let df = DataFrame::new(vec![
+        Column::new("g".into(), ["x", "x", "y", "y"]),
+        sketches.into_column(),
+    ])?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("sketch").tdigest_merge()])
+        .select([col("g"), col("sketch").tdigest_quantile(0.5)])
+        .collect()?;
+
+    assert_eq!(out.column("sketch")?.dtype(), &DataType::Float64);
+    let medians = out.column("sketch")?.f64()?;
+    assert!((medians.get(0).unwrap() - 500.0).abs() < 5.0);
+    assert!((medians.get(1).unwrap() - 50.0).abs() < 2.0);
+    Ok(())
+}
@@ -354,3 +354,47 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_filter_predicate() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let sum = (col("a") + col("b")) * lit(2);
+    let q = df
+        .lazy()
+        .filter(sum.clone().gt(lit(10)).and(sum.lt(lit(20))))
+        .deduplicate_expressions();
+
+    // The shared sub-expression is computed once into a temporary column before the filter.
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = q.clone().to_alp_optimized()?;
+    let cse_columns = lp_arena
+        .iter(lp_top)
+        .filter_map(|(_, lp)| match lp {
+            IR::HStack { exprs, .. } => Some(exprs),
+            _ => None,
+        })
+        .flatten()
+        .filter(|e| {
+            e.output_name()
+                .starts_with(polars_plan::constants::CSE_REPLACED)
+        })
+        .count();
+    assert_eq!(cse_columns, 1);
+    assert!(
+        lp_arena
+            .iter(lp_top)
+            .any(|(_, lp)| matches!(lp, IR::Filter { .. }))
+    );
+
+    let out = q.collect()?;
+
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(4)]);
+
+    Ok(())
+}
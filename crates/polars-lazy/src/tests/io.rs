@@ -504,6 +504,35 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "json", feature = "new_streaming"))]
+fn test_sink_json_partitioned_by_key() -> PolarsResult<()> {
+    let base = std::env::temp_dir().join(format!(
+        "polars-sink-json-partitioned-{}",
+        std::process::id()
+    ));
+    let df = df![
+        "g" => ["a", "b", "a"],
+        "x" => [1, 2, 3]
+    ]?;
+    df.lazy().sink_json_partitioned_by_key(
+        base.to_str().unwrap(),
+        vec![col("g")],
+        Default::default(),
+        None,
+    )?;
+
+    let a = base.join("g=a").join("data.ndjson");
+    let b = base.join("g=b").join("data.ndjson");
+    assert!(a.exists() && b.exists());
+    let out = LazyJsonLineReader::new(PlPath::new(a.to_str().unwrap()))
+        .finish()?
+        .collect()?;
+    std::fs::remove_dir_all(&base)?;
+    assert_eq!(Vec::from(out.column("x")?.i64()?), &[Some(1), Some(3)]);
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
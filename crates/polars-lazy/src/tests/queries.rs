@@ -55,6 +55,7 @@ fn test_lazy_unpivot() {
         index: by_name(["petal_width", "petal_length"], true),
         variable_name: None,
         value_name: None,
+        variable_column_prefix: None,
     };
 
     let out = df
@@ -1704,6 +1705,149 @@ fn test_single_ranked_group() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "rank")]
+fn test_ntile_over() -> PolarsResult<()> {
+    let df = df![
+        "group" => [1, 1, 1, 1, 2, 2, 2],
+        "value" => [40, 10, 30, 20, 3, 1, 2]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("value").ntile(2).over([col("group")]).alias("by_value"),
+            // All values of a group tie, so they are ranked in the `order_by` order.
+            col("group")
+                .ntile(2)
+                .over_with_options(
+                    Some([col("group")]),
+                    Some(([col("value")], SortMultipleOptions::default())),
+                    Default::default(),
+                )?
+                .alias("by_order"),
+        ])
+        .collect()?;
+
+    // The group of 3 values gets buckets of 2 and 1 values.
+    let expected = [2, 1, 2, 1, 2, 1, 1].map(Some);
+    assert_eq!(Vec::from(out.column("by_value")?.idx()?), expected);
+    assert_eq!(Vec::from(out.column("by_order")?.idx()?), expected);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cse")]
+fn test_cache_shared() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df!["a" => [1, 2, 3]]?;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let base = {
+        let calls = calls.clone();
+        df.lazy()
+            .select([col("a").map(
+                move |c| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    Ok(Some(c))
+                },
+                GetOutput::same_type(),
+            )])
+            .with_comm_subplan_elim(false)
+    };
+    let lhs = base.clone().filter(col("a").gt(lit(1)));
+    let rhs = base.select([col("a").sum()]);
+
+    let collect = |lhs: LazyFrame, rhs: LazyFrame| {
+        let opt_state = lhs.opt_state;
+        LazyFrame::collect_all_with_engine(
+            vec![lhs.logical_plan, rhs.logical_plan],
+            Engine::InMemory,
+            opt_state,
+        )
+    };
+
+    collect(lhs.clone(), rhs.clone())?;
+    assert_eq!(calls.swap(0, Ordering::Relaxed), 2);
+
+    let (lhs, rhs) = LazyFrame::cache_shared(lhs, rhs);
+    let out = collect(lhs, rhs)?;
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(out[0].height(), 2);
+    assert_eq!(out[1].column("a")?.get(0)?, AnyValue::Int32(6));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cse")]
+fn test_cache_shared_independent_frames() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df!["a" => [1, 2, 3]]?;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let udf = {
+        let calls = calls.clone();
+        col("a").map(
+            move |c| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(c))
+            },
+            GetOutput::same_type(),
+        )
+    };
+    // Structurally equal, but built independently.
+    let build = || {
+        df.clone()
+            .lazy()
+            .select([udf.clone()])
+            .with_comm_subplan_elim(false)
+    };
+    let lhs = build().filter(col("a").gt(lit(1)));
+    let rhs = build().select([col("a").sum()]);
+
+    let (lhs, rhs) = LazyFrame::cache_shared(lhs, rhs);
+    assert!(lhs.describe_plan()?.contains("CACHE"));
+    assert!(rhs.describe_plan()?.contains("CACHE"));
+
+    let opt_state = lhs.opt_state;
+    let out = LazyFrame::collect_all_with_engine(
+        vec![lhs.logical_plan, rhs.logical_plan],
+        Engine::InMemory,
+        opt_state,
+    )?;
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(out[0].height(), 2);
+    assert_eq!(out[1].column("a")?.get(0)?, AnyValue::Int32(6));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ir_serde")]
+fn test_optimized_plan_roundtrip() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => ["x", "y", "x", "y"]
+    ]?;
+    let lf = df
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .select([col("b"), (col("a") * lit(2)).alias("a2")]);
+    let expected = lf.clone().collect()?;
+
+    let bytes = lf.optimize_and_serialize()?;
+    let out = LazyFrame::from_optimized_bytes(&bytes)?.collect()?;
+    assert!(out.equals(&expected));
+
+    // Plans serialized by another Polars version are rejected.
+    let mut other_version = bytes.clone();
+    let version_start = b"OPTIMIZED_PLAN_VERSION".len() + 1;
+    other_version[version_start] ^= 1;
+    assert!(LazyFrame::from_optimized_bytes(&other_version).is_err());
+    assert!(LazyFrame::from_optimized_bytes(&bytes[1..]).is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "diff")]
 fn empty_df() -> PolarsResult<()> {
@@ -1950,6 +2094,27 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_sort_by_exprs_stable() -> PolarsResult<()> {
+    let df = df![
+        "A" => [2, 1, 2, 1, 2],
+        "B" => ["a", "b", "c", "d", "e"],
+    ]?;
+
+    let res = df
+        .lazy()
+        .sort_by_exprs_stable(
+            [col("A")],
+            SortMultipleOptions::default().with_order_descending(true),
+        )
+        .collect()?;
+    assert!(res.equals(&df![
+        "A" => [2, 2, 2, 1, 1],
+        "B" => ["a", "c", "e", "b", "d"],
+    ]?));
+    Ok(())
+}
+
 #[test]
 fn test_over_with_options_empty_join() -> PolarsResult<()> {
     let empty_df = DataFrame::new(vec![
@@ -1973,3 +2138,186 @@ fn test_over_with_options_empty_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_with_plan_metadata() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?;
+
+    let lf = df
+        .clone()
+        .lazy()
+        .with_plan_metadata("my_label")
+        .filter(col("a").gt(lit(1)))
+        .select([col("a")]);
+    assert!(lf.explain(true)?.contains("LABEL: my_label"));
+
+    let out = lf.collect()?;
+    assert!(out.equals(&df!["a" => [2, 3]]?));
+    Ok(())
+}
+
+#[test]
+fn test_with_new_schema() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::UInt64),
+        Field::new("c".into(), DataType::String),
+    ]);
+    let out = df.clone().lazy().with_new_schema(schema)?.collect()?;
+    assert!(out.equals(&df![
+        "a" => [1u64, 2, 3],
+        "c" => ["x", "y", "z"],
+    ]?));
+
+    let schema = Schema::from_iter([Field::new("a".into(), DataType::UInt64)]);
+    assert!(df.lazy().with_new_schema(schema).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "iejoin")]
+fn test_join_where() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 1, 2],
+        "t" => [1, 5, 3],
+    ]?;
+    let right = df![
+        "id_right" => [1, 2],
+        "start" => [2, 1],
+    ]?;
+
+    let out = left
+        .clone()
+        .lazy()
+        .join_where(
+            right.clone().lazy(),
+            [col("id").eq(col("id_right")), col("t").gt_eq(col("start"))],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .select([col("id"), col("t")])
+        .sort(["t"], Default::default())
+        .collect()?;
+
+    assert!(out.equals(&df![
+        "id" => [2, 1],
+        "t" => [3, 5],
+    ]?));
+
+    let predicates = [col("id").eq(col("id_right")), col("t").gt_eq(col("start"))];
+    let mut args = JoinArgs::new(JoinType::Inner);
+    args.slice = Some((0, 1));
+    let out = left
+        .clone()
+        .lazy()
+        .join_where(right.clone().lazy(), predicates.clone(), args)
+        .collect()?;
+    assert_eq!(out.height(), 1);
+
+    let out = left
+        .lazy()
+        .join_where(right.lazy(), predicates, JoinArgs::new(JoinType::Left))
+        .collect();
+    assert!(
+        out.unwrap_err()
+            .to_string()
+            .contains("only supports inner joins")
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "iejoin", feature = "semi_anti_join"))]
+fn test_semi_anti_join_where() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 1, 2, 3],
+        "t" => [1, 5, 3, 0],
+    ]?;
+    let right = df![
+        "id_right" => [1, 1, 2],
+        "start" => [2, 3, 1],
+    ]?;
+    let predicate = col("id")
+        .eq(col("id_right"))
+        .and(col("t").gt_eq(col("start")));
+
+    // Rows matching several rows of `right` are kept once.
+    let out = left
+        .clone()
+        .lazy()
+        .semi_join_where(right.clone().lazy(), predicate.clone())
+        .sort(["t"], Default::default())
+        .collect()?;
+    assert!(out.equals(&df![
+        "id" => [2, 1],
+        "t" => [3, 5],
+    ]?));
+
+    let out = left
+        .lazy()
+        .anti_join_where(right.lazy(), predicate)
+        .sort(["t"], Default::default())
+        .collect()?;
+    assert!(out.equals(&df![
+        "id" => [3, 1],
+        "t" => [0, 1],
+    ]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_join_asof_by() -> PolarsResult<()> {
+    use polars_ops::frame::AsofStrategy;
+
+    let left = df![
+        "sensor" => [1, 2, 1, 2],
+        "t" => [1, 2, 3, 4],
+    ]?;
+    let right = df![
+        "sensor" => [1, 2, 1],
+        "t" => [0, 1, 2],
+        "v" => [10, 20, 30],
+    ]?;
+
+    let out = left
+        .clone()
+        .lazy()
+        .join_asof_by(
+            right.clone().lazy(),
+            col("t"),
+            vec![col("sensor")],
+            vec![col("sensor")],
+            AsofStrategy::Backward,
+            None,
+            None,
+        )
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(10), Some(20), Some(30), Some(20)]
+    );
+
+    // A `by` expression without an output name is rejected when the plan is resolved.
+    let out = left
+        .lazy()
+        .join_asof_by(
+            right.lazy(),
+            col("t"),
+            vec![(col("sensor") + lit(1)).name().keep()],
+            vec![col("sensor")],
+            AsofStrategy::Backward,
+            None,
+            None,
+        )
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
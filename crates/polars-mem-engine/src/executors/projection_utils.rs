@@ -161,14 +161,7 @@ fn execute_projection_cached_window_fns(
                     let entry = match options {
                         WindowType::Over(g) => {
                             let g: &str = g.into();
-                            let mut key = format!("{:?}_{}", partition_by.as_slice(), g);
-                            if let Some((e, k)) = order_by {
-                                polars_expr::prelude::window_function_format_order_by(
-                                    &mut key,
-                                    e.as_ref(),
-                                    k,
-                                )
-                            }
+                            let key = format!("{:?}_{}_{:?}", partition_by.as_slice(), g, order_by);
                             windows.entry(key).or_insert_with(Vec::new)
                         },
                         #[cfg(feature = "dynamic_group_by")]
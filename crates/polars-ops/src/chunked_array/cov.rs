@@ -1,7 +1,12 @@
+use std::cmp::Ordering;
+
 use num_traits::AsPrimitive;
 use polars_compute::moment::{CovState, PearsonState};
+use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_core::utils::align_chunks_binary;
+use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
 
 /// Compute the covariance between two columns.
 pub fn cov<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>, ddof: u8) -> Option<f64>
@@ -32,3 +37,273 @@ where
     }
     Some(out.finalize())
 }
+
+/// The method used by [`correlation`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CorrelationMethod {
+    #[default]
+    Pearson,
+    /// Pearson correlation of the average ranks.
+    Spearman,
+    /// Kendall's tau-b, which accounts for ties.
+    Kendall,
+}
+
+/// Kendall's tau-b of two equal-length slices.
+///
+/// This compares all pairs of observations, so it is `O(n^2)`.
+fn kendall_tau_b(a: &[f64], b: &[f64]) -> f64 {
+    let (mut concordant, mut discordant) = (0u64, 0u64);
+    let (mut ties_a, mut ties_b) = (0u64, 0u64);
+    for i in 0..a.len() {
+        for j in i + 1..a.len() {
+            match (a[i].tot_cmp(&a[j]), b[i].tot_cmp(&b[j])) {
+                (Ordering::Equal, Ordering::Equal) => {},
+                (Ordering::Equal, _) => ties_a += 1,
+                (_, Ordering::Equal) => ties_b += 1,
+                (ord_a, ord_b) if ord_a == ord_b => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+
+    let untied = concordant + discordant;
+    (concordant as f64 - discordant as f64)
+        / (((untied + ties_a) * (untied + ties_b)) as f64).sqrt()
+}
+
+/// Compute the correlation between two numeric [`Series`].
+///
+/// Rows in which either value is null are ignored. If no more than `ddof` rows remain, the
+/// result is `NaN`.
+pub fn correlation(
+    a: &Series,
+    b: &Series,
+    method: CorrelationMethod,
+    ddof: u8,
+) -> PolarsResult<f64> {
+    polars_ensure!(
+        a.len() == b.len(),
+        ShapeMismatch: "cannot compute the correlation of series of lengths {} and {}",
+        a.len(), b.len()
+    );
+    for s in [a, b] {
+        polars_ensure!(
+            s.dtype().is_primitive_numeric() || s.dtype().is_bool(),
+            InvalidOperation: "cannot compute the correlation of '{}' with dtype {}",
+            s.name(), s.dtype()
+        );
+    }
+
+    let mut a = a.cast(&DataType::Float64)?;
+    let mut b = b.cast(&DataType::Float64)?;
+    if a.has_nulls() || b.has_nulls() {
+        let mask = a.is_not_null() & b.is_not_null();
+        a = a.filter(&mask)?;
+        b = b.filter(&mask)?;
+    }
+    if a.len() <= ddof as usize {
+        return Ok(f64::NAN);
+    }
+
+    let out = match method {
+        CorrelationMethod::Pearson => pearson_corr(a.f64()?, b.f64()?),
+        #[cfg(feature = "rank")]
+        CorrelationMethod::Spearman => {
+            use crate::series::{RankMethod, RankOptions, SeriesRank};
+
+            let options = RankOptions {
+                method: RankMethod::Average,
+                descending: false,
+            };
+            let a = a.rank(options, None).cast(&DataType::Float64)?;
+            let b = b.rank(options, None).cast(&DataType::Float64)?;
+            pearson_corr(a.f64()?, b.f64()?)
+        },
+        #[cfg(not(feature = "rank"))]
+        CorrelationMethod::Spearman => {
+            polars_bail!(InvalidOperation: "spearman correlation requires the 'rank' feature")
+        },
+        CorrelationMethod::Kendall => {
+            let a = a.rechunk();
+            let b = b.rechunk();
+            Some(kendall_tau_b(
+                a.f64()?.cont_slice()?,
+                b.f64()?.cont_slice()?,
+            ))
+        },
+    };
+    Ok(out.unwrap_or(f64::NAN))
+}
+
+/// Values of a column prepared for computing correlations.
+struct CorrColumn {
+    values: Vec<f64>,
+    validity: Option<Vec<bool>>,
+    /// Deviations from the mean and their sum of squares, only for columns without nulls.
+    deviations: Option<(Vec<f64>, f64)>,
+}
+
+impl CorrColumn {
+    fn new(s: &Series) -> PolarsResult<Self> {
+        polars_ensure!(
+            s.dtype().is_primitive_numeric() || s.dtype().is_bool(),
+            InvalidOperation: "cannot compute the correlation of column '{}' with dtype {}",
+            s.name(), s.dtype()
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+        let values = ca.iter().map(|v| v.unwrap_or(0.0)).collect::<Vec<_>>();
+
+        if ca.has_nulls() {
+            let validity = ca.iter().map(|v| v.is_some()).collect();
+            return Ok(Self {
+                values,
+                validity: Some(validity),
+                deviations: None,
+            });
+        }
+
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let deviations = values.iter().map(|v| v - mean).collect::<Vec<_>>();
+        let sum_sq = deviations.iter().map(|d| d * d).sum();
+        Ok(Self {
+            values,
+            validity: None,
+            deviations: Some((deviations, sum_sq)),
+        })
+    }
+}
+
+/// Compute the pearson correlation of two prepared columns over the rows where both are valid.
+fn corr_pair(a: &CorrColumn, b: &CorrColumn, ddof: u8, min_periods: usize) -> Option<f64> {
+    let min_periods = min_periods.max(ddof as usize + 1);
+
+    if let (Some((dev_a, ss_a)), Some((dev_b, ss_b))) = (&a.deviations, &b.deviations) {
+        if dev_a.len() < min_periods {
+            return None;
+        }
+        let dot = dev_a.iter().zip(dev_b).map(|(x, y)| x * y).sum::<f64>();
+        return Some(dot / (ss_a * ss_b).sqrt());
+    }
+
+    // Pairwise complete observations.
+    let is_valid = |i: usize| {
+        a.validity.as_ref().is_none_or(|v| v[i]) && b.validity.as_ref().is_none_or(|v| v[i])
+    };
+    let pairs = || {
+        (0..a.values.len())
+            .filter(|&i| is_valid(i))
+            .map(|i| (a.values[i], b.values[i]))
+    };
+    let (n, sum_a, sum_b) = pairs().fold((0usize, 0.0, 0.0), |(n, sa, sb), (x, y)| {
+        (n + 1, sa + x, sb + y)
+    });
+    if n < min_periods {
+        return None;
+    }
+    let (mean_a, mean_b) = (sum_a / n as f64, sum_b / n as f64);
+    let (dot, ss_a, ss_b) = pairs().fold((0.0, 0.0, 0.0), |(dot, ss_a, ss_b), (x, y)| {
+        let (dx, dy) = (x - mean_a, y - mean_b);
+        (dot + dx * dy, ss_a + dx * dx, ss_b + dy * dy)
+    });
+    Some(dot / (ss_a * ss_b).sqrt())
+}
+
+/// Compute the pearson correlation matrix of all columns in `df`.
+///
+/// The means and deviations are computed once per column, after which the upper triangle of
+/// the matrix is filled in parallel. Nulls are handled with pairwise complete observations. A
+/// pair with less than `min_periods` (or less than `ddof + 1`) observations results in a null.
+///
+/// The output has one `Float64` column per input column and one row per input column.
+pub fn pearson_corr_matrix(
+    df: &DataFrame,
+    ddof: u8,
+    min_periods: usize,
+) -> PolarsResult<DataFrame> {
+    let columns = POOL.install(|| {
+        df.get_columns()
+            .par_iter()
+            .map(|c| CorrColumn::new(c.as_materialized_series()))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let k = columns.len();
+    let pairs = (0..k)
+        .flat_map(|i| (i..k).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+    let upper = POOL.install(|| {
+        pairs
+            .par_iter()
+            .map(|&(i, j)| corr_pair(&columns[i], &columns[j], ddof, min_periods))
+            .collect::<Vec<_>>()
+    });
+
+    let mut matrix = vec![vec![None; k]; k];
+    for (&(i, j), corr) in pairs.iter().zip(upper) {
+        matrix[i][j] = corr;
+        matrix[j][i] = corr;
+    }
+
+    let out = df
+        .get_column_names()
+        .into_iter()
+        .zip(matrix)
+        .map(|(name, values)| Float64Chunked::new(name.clone(), values).into_column())
+        .collect();
+    DataFrame::new(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_correlation() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [1.0, 2.0, 3.0, 4.0, 5.0]);
+        let b = Series::new("b".into(), [Some(1), Some(4), Some(9), None, Some(25)]);
+
+        let pearson = correlation(&a, &b, CorrelationMethod::Pearson, 1)?;
+        assert!(pearson > 0.9 && pearson < 1.0);
+        let kendall = correlation(&a, &b, CorrelationMethod::Kendall, 1)?;
+        assert!((kendall - 1.0).abs() < 1e-12);
+        #[cfg(feature = "rank")]
+        {
+            let spearman = correlation(&a, &b, CorrelationMethod::Spearman, 1)?;
+            assert!((spearman - 1.0).abs() < 1e-12);
+        }
+
+        let c = Series::new("c".into(), [2.0, 1.0, 1.0, 3.0, 0.0]);
+        // 3 concordant, 6 discordant and 1 pair tied in `c`.
+        let kendall = correlation(&a, &c, CorrelationMethod::Kendall, 1)?;
+        assert!((kendall - (-3.0 / 90f64.sqrt())).abs() < 1e-12);
+
+        assert!(correlation(&a, &b, CorrelationMethod::Pearson, 4)?.is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pearson_corr_matrix() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1.0, 2.0, 3.0, 4.0],
+            "b" => [2.0, 4.0, 6.0, 8.0],
+            "c" => [Some(4.0), Some(3.0), None, Some(1.0)],
+        ]?;
+
+        let out = pearson_corr_matrix(&df, 1, 0)?;
+        assert_eq!(out.shape(), (3, 3));
+
+        let a = out.column("a")?.f64()?;
+        let c = out.column("c")?.f64()?;
+        assert!((a.get(0).unwrap() - 1.0).abs() < 1e-12);
+        assert!((a.get(1).unwrap() - 1.0).abs() < 1e-12);
+        assert!((a.get(2).unwrap() + 1.0).abs() < 1e-12);
+        assert_eq!(a.get(2), c.get(0));
+
+        let out = pearson_corr_matrix(&df, 1, 4)?;
+        assert_eq!(out.column("c")?.null_count(), 3);
+        assert_eq!(out.column("a")?.null_count(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use polars_core::POOL;
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
+use rayon::prelude::*;
+
+/// Inputs longer than this are split over the thread pool.
+const PARALLEL_THRESHOLD: usize = 10_000;
+
+/// The edit distance or similarity computed by
+/// [`string_distance`](super::StringNameSpaceImpl::string_distance).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StringDistanceMethod {
+    /// The number of character insertions, deletions and substitutions.
+    Levenshtein,
+    /// The Levenshtein distance that also counts swapping two adjacent characters as a single
+    /// edit (optimal string alignment).
+    DamerauLevenshtein,
+    /// The Jaro similarity, from `0.0` for no similarity to `1.0` for equal strings.
+    Jaro,
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> u32 {
+    let mut row = (0..=b.len() as u32).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i as u32 + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = diag + u32::from(ca != cb);
+            diag = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diag + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn damerau_levenshtein(a: &[char], b: &[char]) -> u32 {
+    let n = b.len();
+    let mut prev2 = vec![0u32; n + 1];
+    let mut prev = (0..=n as u32).collect::<Vec<_>>();
+    let mut cur = vec![0u32; n + 1];
+    for i in 1..=a.len() {
+        cur[0] = i as u32;
+        for j in 1..=n {
+            let cost = u32::from(a[i - 1] != b[j - 1]);
+            let mut d = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(prev2[j - 2] + 1);
+            }
+            cur[j] = d;
+        }
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[n]
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    // The matched characters of `a`, in order.
+    let mut a_matches = Vec::with_capacity(a.len().min(b.len()));
+    for (i, &c) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        for j in i.saturating_sub(window)..end {
+            if !b_matched[j] && b[j] == c {
+                b_matched[j] = true;
+                a_matches.push(c);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let half_transpositions = b
+        .iter()
+        .zip(&b_matched)
+        .filter_map(|(c, matched)| matched.then_some(c))
+        .zip(&a_matches)
+        .filter(|(b, a)| b != a)
+        .count();
+    let m = a_matches.len() as f64;
+    let t = (half_transpositions / 2) as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+fn apply<T, F>(lhs: &StringChunked, rhs: &StringChunked, f: F) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(&[char], &[char]) -> T::Native + Send + Sync,
+{
+    let op = |a: Option<&str>, b: Option<&str>| {
+        let a = a?.chars().collect::<Vec<_>>();
+        let b = b?.chars().collect::<Vec<_>>();
+        Some(f(&a, &b))
+    };
+
+    let len = lhs.len();
+    if len != rhs.len() || len <= PARALLEL_THRESHOLD {
+        return broadcast_binary_elementwise(lhs, rhs, op);
+    }
+
+    let offsets = _split_offsets(len, POOL.current_num_threads());
+    let chunks: Vec<ChunkedArray<T>> = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let lhs = lhs.slice(offset as i64, len);
+                let rhs = rhs.slice(offset as i64, len);
+                broadcast_binary_elementwise(&lhs, &rhs, &op)
+            })
+            .collect()
+    });
+    let mut chunks = chunks.into_iter();
+    let mut out = chunks.next().unwrap();
+    for ca in chunks {
+        out.append_owned(ca).unwrap();
+    }
+    out
+}
+
+pub(super) fn string_distance(
+    lhs: &StringChunked,
+    rhs: &StringChunked,
+    method: StringDistanceMethod,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        length_mismatch = "string_distance",
+        lhs.len(),
+        rhs.len()
+    );
+
+    let out = match method {
+        StringDistanceMethod::Levenshtein => {
+            apply::<UInt32Type, _>(lhs, rhs, levenshtein).into_series()
+        },
+        StringDistanceMethod::DamerauLevenshtein => {
+            apply::<UInt32Type, _>(lhs, rhs, damerau_levenshtein).into_series()
+        },
+        StringDistanceMethod::Jaro => apply::<Float64Type, _>(lhs, rhs, jaro).into_series(),
+    };
+    Ok(out.with_name(lhs.name().clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_edit_distances() {
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("ca"), &chars("ac")), 2);
+        assert_eq!(damerau_levenshtein(&chars("ca"), &chars("ac")), 1);
+        assert_eq!(damerau_levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(damerau_levenshtein(&chars("héllo"), &chars("hélol")), 1);
+    }
+
+    #[test]
+    fn test_jaro() {
+        let sim = jaro(&chars("MARTHA"), &chars("MARHTA"));
+        assert!((sim - 0.944444).abs() < 1e-6);
+        assert_eq!(jaro(&chars("abc"), &chars("abc")), 1.0);
+        assert_eq!(jaro(&chars("abc"), &chars("xyz")), 0.0);
+        assert_eq!(jaro(&chars(""), &chars("")), 1.0);
+    }
+
+    #[test]
+    fn test_string_distance() -> PolarsResult<()> {
+        let lhs = StringChunked::new("a".into(), [Some("kitten"), None, Some("ca")]);
+        let rhs = StringChunked::new("b".into(), [Some("sitting"), Some("x"), Some("ac")]);
+
+        let out = string_distance(&lhs, &rhs, StringDistanceMethod::Levenshtein)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.u32()?), &[Some(3), None, Some(2)]);
+
+        let rhs = StringChunked::new("b".into(), ["ac"]);
+        let out = string_distance(&lhs, &rhs, StringDistanceMethod::DamerauLevenshtein)?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(6), None, Some(1)]);
+
+        let out = string_distance(&lhs, &rhs, StringDistanceMethod::Jaro)?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+
+        let rhs = StringChunked::new("b".into(), ["a", "b"]);
+        assert!(string_distance(&lhs, &rhs, StringDistanceMethod::Jaro).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_distance_parallel() -> PolarsResult<()> {
+        let n = PARALLEL_THRESHOLD + 1;
+        let lhs = StringChunked::from_iter_values("a".into(), (0..n).map(|i| i.to_string()));
+        let rhs = StringChunked::from_iter_values("b".into(), (0..n).map(|i| format!("{i}x")));
+
+        let out = string_distance(&lhs, &rhs, StringDistanceMethod::Levenshtein)?;
+        assert_eq!(out.len(), n);
+        assert!(out.u32()?.into_no_null_iter().all(|d| d == 1));
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 mod case;
 #[cfg(feature = "strings")]
 mod concat;
+#[cfg(feature = "string_distance")]
+mod distance;
 #[cfg(feature = "strings")]
 mod escape_regex;
 #[cfg(feature = "strings")]
@@ -29,6 +31,8 @@ mod unicode_internals;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
+#[cfg(feature = "string_distance")]
+pub use distance::StringDistanceMethod;
 #[cfg(feature = "strings")]
 pub use escape_regex::*;
 #[cfg(feature = "find_many")]
@@ -657,6 +657,20 @@ pub trait StringNameSpaceImpl: AsString {
         normalize::normalize(ca, form)
     }
 
+    /// Compute the edit distance or similarity between the strings of `self` and `other`.
+    ///
+    /// The Levenshtein distances are returned as `UInt32` and the Jaro similarity as `Float64`.
+    /// The result is null where either string is null. A unit length input is broadcast.
+    #[cfg(feature = "string_distance")]
+    fn string_distance(
+        &self,
+        other: &StringChunked,
+        method: StringDistanceMethod,
+    ) -> PolarsResult<Series> {
+        let ca = self.as_string();
+        distance::string_distance(ca, other, method)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
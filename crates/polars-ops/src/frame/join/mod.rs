@@ -205,6 +205,23 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
+        // Duration keys with different time units are compared in nanoseconds.
+        #[cfg(feature = "dtype-duration")]
+        for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+            if let (DataType::Duration(tu_l), DataType::Duration(tu_r)) = (l.dtype(), r.dtype()) {
+                if tu_l != tu_r {
+                    polars_ensure!(
+                        !(should_coalesce && matches!(args.how, JoinType::Full)),
+                        ComputeError: "cannot coalesce full join on duration keys with different time units - `{}`: {} on left, `{}`: {} on right",
+                        l.name(), l.dtype(), r.name(), r.dtype()
+                    );
+                    let dtype = DataType::Duration(TimeUnit::Nanoseconds);
+                    *l = l.strict_cast(&dtype)?;
+                    *r = r.strict_cast(&dtype)?;
+                }
+            }
+        }
+
         if let Some((l, r)) = selected_left
             .iter()
             .zip(&selected_right)
@@ -122,4 +122,154 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Compute the correlation between the columns `col_a` and `col_b`.
+    ///
+    /// See [`correlation`](crate::chunked_array::cov::correlation).
+    #[cfg(feature = "cov")]
+    fn correlation(
+        &self,
+        col_a: &str,
+        col_b: &str,
+        method: crate::chunked_array::cov::CorrelationMethod,
+        ddof: u8,
+    ) -> PolarsResult<f64> {
+        let df = self.to_df();
+        crate::chunked_array::cov::correlation(
+            df.column(col_a)?.as_materialized_series(),
+            df.column(col_b)?.as_materialized_series(),
+            method,
+            ddof,
+        )
+    }
+
+    /// Compute summary statistics of every column.
+    ///
+    /// The result has a `statistic` column with the names of the statistics and a `Float64`
+    /// column per column of this [`DataFrame`]. All columns get a `count` and `null_count`.
+    /// Numeric columns also get the `mean`, `std`, `min`, the given `percentiles`, `max`, `skew`,
+    /// `kurtosis`, the `iqr` (interquartile range) and the `mad` (median absolute deviation).
+    /// String columns get the `min_len` and `max_len` in characters. Statistics that don't apply
+    /// to a column are null.
+    #[cfg(feature = "moment")]
+    fn describe_extended(&self, percentiles: &[f64]) -> PolarsResult<DataFrame> {
+        use crate::series::MomentSeries;
+
+        polars_ensure!(
+            percentiles.iter().all(|p| (0.0..=1.0).contains(p)),
+            InvalidOperation: "percentiles must be between 0 and 1, got {:?}", percentiles
+        );
+        let df = self.to_df();
+
+        let mut statistics = vec![
+            "count".to_string(),
+            "null_count".into(),
+            "mean".into(),
+            "std".into(),
+            "min".into(),
+        ];
+        statistics.extend(percentiles.iter().map(|p| format!("{}%", p * 100.0)));
+        statistics.extend(
+            [
+                "max", "skew", "kurtosis", "iqr", "mad", "min_len", "max_len",
+            ]
+            .map(String::from),
+        );
+        let n = statistics.len();
+
+        let mut columns = Vec::with_capacity(df.width() + 1);
+        columns.push(
+            StringChunked::from_iter_values(
+                PlSmallStr::from_static("statistic"),
+                statistics.iter().map(|s| s.as_str()),
+            )
+            .into_column(),
+        );
+
+        for c in df.get_columns() {
+            let s = c.as_materialized_series();
+            let mut values = vec![None; n];
+            values[0] = Some((s.len() - s.null_count()) as f64);
+            values[1] = Some(s.null_count() as f64);
+
+            if s.dtype().is_primitive_numeric() {
+                let s = s.cast(&DataType::Float64)?;
+                let ca = s.f64()?;
+                let quantile = |q| ca.quantile(q, QuantileMethod::Linear);
+
+                let mut numeric = vec![ca.mean(), ca.std(1), ca.min()];
+                for &p in percentiles {
+                    numeric.push(quantile(p)?);
+                }
+                let iqr = quantile(0.75)?
+                    .zip(quantile(0.25)?)
+                    .map(|(q75, q25)| q75 - q25);
+                numeric.extend([
+                    ca.max(),
+                    s.skew(true)?,
+                    s.kurtosis(true, true)?,
+                    iqr,
+                    s.mad(0)?,
+                ]);
+                values[2..2 + numeric.len()].copy_from_slice(&numeric);
+            } else if let Ok(ca) = s.str() {
+                let (min, max) = ca.iter().flatten().map(|v| v.chars().count() as f64).fold(
+                    (None, None),
+                    |(min, max): (Option<f64>, Option<f64>), len| {
+                        (
+                            Some(min.map_or(len, |m| m.min(len))),
+                            Some(max.map_or(len, |m| m.max(len))),
+                        )
+                    },
+                );
+                values[n - 2] = min;
+                values[n - 1] = max;
+            }
+
+            columns
+                .push(Float64Chunked::from_slice_options(c.name().clone(), &values).into_column());
+        }
+
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "moment")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_describe_extended() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3, 4, 5, 23],
+            "b" => [Some("a"), Some("bcd"), None, Some("ef"), Some(""), Some("ghij")],
+        ]?;
+
+        let out = df.describe_extended(&[0.5])?;
+        assert_eq!(out.get_column_names(), &["statistic", "a", "b"]);
+        let get = |col: &str, statistic: &str| -> PolarsResult<Option<f64>> {
+            let idx = out
+                .column("statistic")?
+                .str()?
+                .iter()
+                .position(|s| s == Some(statistic))
+                .unwrap();
+            Ok(out.column(col)?.f64()?.get(idx))
+        };
+
+        assert_eq!(get("a", "count")?, Some(6.0));
+        assert_eq!(get("a", "50%")?, Some(3.5));
+        assert_eq!(get("a", "iqr")?, Some(2.5));
+        assert_eq!(get("a", "mad")?, Some(1.5));
+        assert!((get("a", "skew")?.unwrap() - 1.6727687946848508).abs() < 1e-4);
+        assert_eq!(get("a", "min_len")?, None);
+        assert_eq!(get("b", "null_count")?, Some(1.0));
+        assert_eq!(get("b", "min_len")?, Some(0.0));
+        assert_eq!(get("b", "max_len")?, Some(4.0));
+        assert_eq!(get("b", "mean")?, None);
+
+        assert!(df.describe_extended(&[1.5]).is_err());
+        Ok(())
+    }
 }
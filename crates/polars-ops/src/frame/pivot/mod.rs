@@ -3,13 +3,14 @@ mod unpivot;
 
 use std::borrow::Cow;
 
+use polars_core::chunked_array::builder::get_list_builder;
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_core::utils::_split_offsets;
 use polars_core::{POOL, downcast_as_macro_arg_physical};
 use polars_utils::format_pl_smallstr;
 use rayon::prelude::*;
-pub use unpivot::UnpivotDF;
+pub use unpivot::{UnpivotDF, pivot_longer_by_regex};
 
 const HASHMAP_INIT_SIZE: usize = 512;
 
@@ -135,6 +136,56 @@ where
     )
 }
 
+/// Count the occurrences of every combination of `index` and `on` values, a.k.a. a
+/// cross-tabulation.
+///
+/// Every unique value of `on` becomes a column holding the counts. Combinations that don't
+/// occur get a count of 0.
+pub fn pivot_counts<I0, I1, S0, S1>(
+    pivot_df: &DataFrame,
+    on: I0,
+    index: I1,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+{
+    const COUNT_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_PIVOT_COUNT");
+
+    let on = on.into_iter().map(Into::into).collect::<Vec<_>>();
+    let index = index.into_iter().map(Into::into).collect::<Vec<_>>();
+
+    let gb = pivot_df.group_by_stable(index.iter().chain(on.iter()).cloned())?;
+    let counts = gb.get_groups().group_count().with_name(COUNT_NAME);
+    let mut counts_df = DataFrame::new(gb.keys())?;
+    counts_df.with_column(counts.into_column())?;
+
+    let out = pivot_stable(
+        &counts_df,
+        on,
+        Some(index.iter().cloned()),
+        Some([COUNT_NAME]),
+        false,
+        None,
+        None,
+    )?;
+
+    let columns = out
+        .take_columns()
+        .into_iter()
+        .map(|c| {
+            if index.contains(c.name()) {
+                Ok(c)
+            } else {
+                c.fill_null(FillNullStrategy::Zero)
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
+
 /// Ensure both `index` and `values` are populated with `Vec<String>`.
 ///
 /// - If `index` is None, assign columns not in `on` and `values` to it.
@@ -243,6 +294,34 @@ fn pivot_impl(
     }
 }
 
+/// Concatenate the lists of a `List` column within every group.
+///
+/// Null lists are ignored. Groups that only contain null lists result in a null.
+///
+/// # Safety
+/// `groups` must be in bounds of `value_col`.
+unsafe fn agg_list_concat(value_col: &Column, groups: &GroupsType) -> PolarsResult<Column> {
+    let DataType::List(inner_dtype) = value_col.dtype() else {
+        unreachable!()
+    };
+    let nested = unsafe { value_col.agg_list(groups) };
+    let nested = nested.list()?;
+
+    let mut builder = get_list_builder(
+        inner_dtype,
+        value_col.len(),
+        nested.len(),
+        value_col.name().clone(),
+    );
+    for lists in nested.amortized_iter() {
+        match lists.map(|lists| lists.as_ref().drop_nulls()) {
+            Some(lists) if !lists.is_empty() => builder.append_series(&lists.explode(true)?)?,
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_column())
+}
+
 fn pivot_impl_single_column(
     pivot_df: &DataFrame,
     index: &[PlSmallStr],
@@ -274,6 +353,9 @@ fn pivot_impl_single_column(
             // Aggregate the expression on a value column
             let value_agg = unsafe {
                 match &agg_fn {
+                    None if matches!(value_col.dtype(), DataType::List(_)) => {
+                        agg_list_concat(value_col, &groups)?
+                    },
                     None => match value_col.len() > groups.len() {
                         true => polars_bail!(
                             ComputeError:
@@ -3,10 +3,11 @@ use arrow::compute::concatenate::concatenate_unchecked;
 use polars_core::datatypes::{DataType, PlSmallStr};
 use polars_core::frame::DataFrame;
 use polars_core::frame::column::Column;
-use polars_core::prelude::{IntoVec, Series, UnpivotArgsIR};
+use polars_core::prelude::{IntoVec, NewChunkedArray, Series, StringChunked, UnpivotArgsIR};
 use polars_core::utils::merge_dtypes_many;
-use polars_error::{PolarsResult, polars_err};
+use polars_error::{PolarsResult, polars_ensure, polars_err, to_compute_err};
 use polars_utils::aliases::PlHashSet;
+use polars_utils::regex_cache::compile_regex;
 
 use crate::frame::IntoDf;
 
@@ -159,7 +160,11 @@ pub trait UnpivotDF: IntoDf {
         let columns = self_.get_columns();
 
         for value_column_name in &on {
-            variable_col.extend_constant(len, Some(value_column_name.as_str()));
+            match &args.variable_column_prefix {
+                Some(prefix) => variable_col
+                    .extend_constant(len, Some(format!("{prefix}{value_column_name}").as_str())),
+                None => variable_col.extend_constant(len, Some(value_column_name.as_str())),
+            }
             // ensure we go via the schema so we are O(1)
             // self.column() is linear
             // together with this loop that would make it O^2 over `on`
@@ -197,6 +202,74 @@ pub trait UnpivotDF: IntoDf {
 
 impl UnpivotDF for DataFrame {}
 
+/// Unpivot all columns whose name matches `pattern`, splitting the column names into multiple
+/// variable columns.
+///
+/// Every capture group of `pattern` becomes a `String` column named after the corresponding
+/// entry of `variable_names`. Columns in `id_cols` are kept as index; other columns that don't
+/// match `pattern` are dropped.
+///
+/// # Example
+///
+/// Unpivoting `metric_2023_Q1` and `metric_2023_Q2` with the pattern `^(\w+)_(\d{4})_(Q\d)$`
+/// and `variable_names` `["metric", "year", "quarter"]` produces the columns
+/// `metric`, `year`, `quarter` and `value_name`, next to the `id_cols`.
+pub fn pivot_longer_by_regex(
+    df: &DataFrame,
+    pattern: &str,
+    variable_names: &[PlSmallStr],
+    value_name: PlSmallStr,
+    id_cols: &[PlSmallStr],
+) -> PolarsResult<DataFrame> {
+    let re = compile_regex(pattern).map_err(to_compute_err)?;
+    polars_ensure!(
+        re.captures_len() - 1 == variable_names.len(),
+        ComputeError: "pattern has {} capture groups, but {} variable names were given",
+        re.captures_len() - 1, variable_names.len()
+    );
+
+    let id_set = PlHashSet::from_iter(id_cols.iter());
+    let (on, captures): (Vec<_>, Vec<_>) = df
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !id_set.contains(name))
+        .filter_map(|name| {
+            let caps = re.captures(name.as_str())?;
+            let caps = (1..caps.len())
+                .map(|i| caps.get(i).map(|m| m.as_str()))
+                .collect::<Vec<_>>();
+            Some((name.clone(), caps))
+        })
+        .unzip();
+    polars_ensure!(
+        !on.is_empty(),
+        ComputeError: "no column names match the pattern '{}'", pattern
+    );
+
+    let variable_name = PlSmallStr::from_static("__POLARS_PIVOT_LONGER_VARIABLE");
+    let mut out = df.unpivot2(UnpivotArgsIR {
+        on,
+        index: id_cols.to_vec(),
+        variable_name: Some(variable_name.clone()),
+        value_name: Some(value_name),
+        variable_column_prefix: None,
+    })?;
+    out.drop_in_place(&variable_name)?;
+
+    let height = df.height();
+    let value_idx = out.width() - 1;
+    for (i, name) in variable_names.iter().enumerate() {
+        let ca = StringChunked::from_iter_options(
+            name.clone(),
+            captures
+                .iter()
+                .flat_map(|caps| std::iter::repeat_n(caps[i], height)),
+        );
+        out.insert_column(value_idx + i, ca)?;
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod test {
     use polars_core::df;
@@ -230,6 +303,7 @@ mod test {
             index: vec!["A".into(), "B".into()],
             variable_name: Some("custom_variable".into()),
             value_name: Some("custom_value".into()),
+            variable_column_prefix: None,
         };
         let unpivoted = df.unpivot2(args).unwrap();
         assert_eq!(
@@ -289,6 +363,56 @@ mod test {
         );
         assert_eq!(unpivoted.len(), 0);
 
+        // Prefix the variable names
+        let args = UnpivotArgsIR {
+            on: vec!["C".into(), "D".into()],
+            index: vec!["A".into()],
+            ..Default::default()
+        }
+        .with_variable_column_prefix("col_");
+        let unpivoted = df.unpivot2(args).unwrap();
+        let variable = unpivoted.column("variable")?;
+        let variable = variable.str()?;
+        let variable = variable.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(
+            variable,
+            &["col_C", "col_C", "col_C", "col_D", "col_D", "col_D"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_longer_by_regex() -> PolarsResult<()> {
+        let df = df!(
+            "id" => &[1, 2],
+            "sales_2023_Q1" => &[10, 20],
+            "sales_2024_Q2" => &[30, 40],
+            "other" => &[0, 0]
+        )?;
+
+        let out = pivot_longer_by_regex(
+            &df,
+            r"^(\w+)_(\d{4})_(Q\d)$",
+            &["metric".into(), "year".into(), "quarter".into()],
+            "amount".into(),
+            &["id".into()],
+        )?;
+        assert_eq!(
+            out.get_column_names(),
+            &["id", "metric", "year", "quarter", "amount"]
+        );
+        assert_eq!(
+            Vec::from(out.column("year")?.str()?),
+            &[Some("2023"), Some("2023"), Some("2024"), Some("2024")]
+        );
+        assert_eq!(
+            Vec::from(out.column("amount")?.i32()?),
+            &[Some(10), Some(20), Some(30), Some(40)]
+        );
+
+        let out = pivot_longer_by_regex(&df, r"^(\w+)_(\d{4})$", &["a".into()], "v".into(), &[]);
+        assert!(out.is_err());
         Ok(())
     }
 }
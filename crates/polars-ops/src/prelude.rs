@@ -6,6 +6,6 @@ pub use crate::chunked_array::*;
 pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
 #[cfg(feature = "pivot")]
-pub use crate::frame::pivot::UnpivotDF;
+pub use crate::frame::pivot::{UnpivotDF, pivot_counts, pivot_longer_by_regex};
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
 pub use crate::series::*;
@@ -53,6 +53,46 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
     })
 }
 
+/// How null bounds are handled in [`clamp_series`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ClampNullStrategy {
+    /// A null bound produces a null.
+    #[default]
+    Propagate,
+    /// A null bound is treated as unbounded, like in [`clip`].
+    Ignore,
+}
+
+/// Clamp every value of `s` between the element-wise bounds `lower` and `upper`.
+///
+/// `lower` and `upper` are broadcast if they have length 1. Null values in `s` always produce
+/// a null; null bounds are handled according to `null_strategy`.
+pub fn clamp_series(
+    s: &Series,
+    lower: &Series,
+    upper: &Series,
+    null_strategy: ClampNullStrategy,
+) -> PolarsResult<Series> {
+    let out = clip(s, lower, upper)?;
+    if null_strategy == ClampNullStrategy::Ignore
+        || (lower.null_count() == 0 && upper.null_count() == 0)
+    {
+        return Ok(out);
+    }
+
+    let len = out.len();
+    let is_valid = |bound: &Series| {
+        let mask = bound.is_not_null();
+        if mask.len() == 1 && len != 1 {
+            mask.new_from_index(0, len)
+        } else {
+            mask
+        }
+    };
+    let mask = is_valid(lower) & is_valid(upper);
+    out.zip_with(&mask, &Series::new_null(PlSmallStr::EMPTY, len))
+}
+
 /// Set values above the given maximum to the maximum value.
 pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
     polars_ensure!(
@@ -208,3 +248,22 @@ where
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clamp_series() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1), Some(5), Some(10), None]);
+        let lower = Series::new("lower".into(), [Some(2), None, Some(2), Some(2)]);
+        let upper = Series::new("upper".into(), [8]);
+
+        let out = clamp_series(&s, &lower, &upper, ClampNullStrategy::Propagate)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), None, Some(8), None]);
+
+        let out = clamp_series(&s, &lower, &upper, ClampNullStrategy::Ignore)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), Some(5), Some(8), None]);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use arrow::array::OwnedArray;
 pub use arrow::legacy::kernels::ewm::EWMOptions;
 use arrow::legacy::kernels::ewm::{
     ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std, ewm_var as kernel_ewm_var,
@@ -21,7 +22,7 @@ pub fn ewm_mean(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
                 options.min_periods,
                 options.ignore_nulls,
             );
-            Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
+            Series::try_from(OwnedArray::new(s.name().clone(), Box::new(result)))
         },
         DataType::Float64 => {
             let xs = s.f64().unwrap();
@@ -32,7 +33,7 @@ pub fn ewm_mean(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
                 options.min_periods,
                 options.ignore_nulls,
             );
-            Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
+            Series::try_from(OwnedArray::new(s.name().clone(), Box::new(result)))
         },
         _ => ewm_mean(&s.cast(&DataType::Float64)?, options),
     }
@@ -51,7 +52,7 @@ pub fn ewm_std(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
                 options.min_periods,
                 options.ignore_nulls,
             );
-            Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
+            Series::try_from(OwnedArray::new(s.name().clone(), Box::new(result)))
         },
         DataType::Float64 => {
             let xs = s.f64().unwrap();
@@ -63,7 +64,7 @@ pub fn ewm_std(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
                 options.min_periods,
                 options.ignore_nulls,
             );
-            Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
+            Series::try_from(OwnedArray::new(s.name().clone(), Box::new(result)))
         },
         _ => ewm_std(&s.cast(&DataType::Float64)?, options),
     }
@@ -82,7 +83,7 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
                 options.min_periods,
                 options.ignore_nulls,
             );
-            Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
+            Series::try_from(OwnedArray::new(s.name().clone(), Box::new(result)))
         },
         DataType::Float64 => {
             let xs = s.f64().unwrap();
@@ -94,7 +95,7 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
                 options.min_periods,
                 options.ignore_nulls,
             );
-            Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
+            Series::try_from(OwnedArray::new(s.name().clone(), Box::new(result)))
         },
         _ => ewm_var(&s.cast(&DataType::Float64)?, options),
     }
@@ -366,6 +366,33 @@ pub fn coalesce_columns(s: &[Column]) -> PolarsResult<Column> {
     Ok(out)
 }
 
+/// Fill the nulls of `primary` with the values of the `fallbacks`, from left to right.
+///
+/// Series of length 1 are broadcast to the length of the other inputs. All inputs must have the
+/// same dtype.
+pub fn coalesce_series(primary: &Series, fallbacks: &[&Series]) -> PolarsResult<Series> {
+    let len = std::iter::once(primary)
+        .chain(fallbacks.iter().copied())
+        .map(|s| s.len())
+        .find(|&len| len != 1)
+        .unwrap_or(1);
+
+    let mut out = primary.broadcast_to(len)?;
+    for fallback in fallbacks {
+        if !out.has_nulls() {
+            break;
+        }
+        polars_ensure!(
+            fallback.dtype() == out.dtype(),
+            SchemaMismatch: "cannot coalesce dtypes {} and {}", out.dtype(), fallback.dtype()
+        );
+        let fallback = fallback.broadcast_to(len)?;
+        let mask = out.is_not_null();
+        out = out.zip_with_same_type(&mask, &fallback)?;
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -407,4 +434,19 @@ mod tests {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    fn test_coalesce_series() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [Some(1), None, None]);
+        let b = Series::new("b".into(), [None, Some(2), None]);
+        let c = Series::new("c".into(), [3]);
+
+        let out = coalesce_series(&a, &[&b, &c])?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), Some(3)]);
+
+        let d = Series::new("d".into(), [1, 2]);
+        assert!(coalesce_series(&a, &[&d]).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,123 @@
+use polars_core::prelude::*;
+
+/// Find the cell of `coords` that contains `v` and the relative position of `v`
+/// within that cell. Returns `None` if `v` lies outside of `coords`.
+#[inline]
+fn locate(coords: &[f64], v: f64) -> Option<(usize, f64)> {
+    let first = coords[0];
+    let last = coords[coords.len() - 1];
+    // Also catches NaN.
+    if !(v >= first && v <= last) {
+        return None;
+    }
+    let idx = coords
+        .partition_point(|c| *c <= v)
+        .saturating_sub(1)
+        .min(coords.len() - 2);
+    let (low, high) = (coords[idx], coords[idx + 1]);
+    Some((idx, (v - low) / (high - low)))
+}
+
+fn ensure_strictly_increasing(coords: &[f64], name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        coords.len() >= 2,
+        ComputeError: "bilinear interpolation requires at least 2 `{}` values, got {}", name, coords.len()
+    );
+    polars_ensure!(
+        coords.windows(2).all(|w| w[0] < w[1]),
+        ComputeError: "`{}` must be strictly increasing for bilinear interpolation", name
+    );
+    Ok(())
+}
+
+/// Sample a 2-D `grid` at the points `(x[i], y[i])` using bilinear interpolation.
+///
+/// The columns of `grid` correspond to `x_coords` and its rows to `y_coords`, so
+/// `grid[j][i]` holds the value at `(x_coords[j], y_coords[i])`. Both coordinate
+/// slices must be strictly increasing.
+///
+/// Points outside of the grid, null points and points that have a null among
+/// their four neighbouring grid values produce a null.
+pub fn bilinear_interp(
+    x: &Series,
+    y: &Series,
+    grid: &DataFrame,
+    x_coords: &[f64],
+    y_coords: &[f64],
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "`x` and `y` must have the same length, got {} and {}", x.len(), y.len()
+    );
+    ensure_strictly_increasing(x_coords, "x_coords")?;
+    ensure_strictly_increasing(y_coords, "y_coords")?;
+    polars_ensure!(
+        grid.width() == x_coords.len() && grid.height() == y_coords.len(),
+        ShapeMismatch: "grid of shape {:?} does not match coordinates of shape ({}, {})",
+        grid.shape(), y_coords.len(), x_coords.len()
+    );
+
+    let columns = grid
+        .get_columns()
+        .iter()
+        .map(|c| {
+            let c = c.cast(&DataType::Float64)?;
+            Ok(c.f64()?.rechunk().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let value = |col: usize, row: usize| columns[col].get(row);
+
+    let x = x.cast(&DataType::Float64)?;
+    let y = y.cast(&DataType::Float64)?;
+
+    let iter = x.f64()?.into_iter().zip(y.f64()?).map(|(x, y)| {
+        let (col, tx) = locate(x_coords, x?)?;
+        let (row, ty) = locate(y_coords, y?)?;
+
+        let q00 = value(col, row)?;
+        let q10 = value(col + 1, row)?;
+        let q01 = value(col, row + 1)?;
+        let q11 = value(col + 1, row + 1)?;
+
+        Some(
+            q00 * (1.0 - tx) * (1.0 - ty)
+                + q10 * tx * (1.0 - ty)
+                + q01 * (1.0 - tx) * ty
+                + q11 * tx * ty,
+        )
+    });
+
+    Ok(Float64Chunked::from_iter_options(x.name().clone(), iter).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bilinear_interp() -> PolarsResult<()> {
+        // f(x, y) = x + 10 * y
+        let grid = df![
+            "x0" => [0.0, 10.0, 20.0],
+            "x1" => [1.0, 11.0, 21.0],
+        ]?;
+        let x_coords = [0.0, 1.0];
+        let y_coords = [0.0, 1.0, 2.0];
+
+        let x = Series::new(
+            "x".into(),
+            [Some(0.5), Some(0.0), Some(1.0), Some(2.0), None],
+        );
+        let y = Series::new(
+            "y".into(),
+            [Some(1.5), Some(0.0), Some(2.0), Some(1.0), Some(1.0)],
+        );
+
+        let out = bilinear_interp(&x, &y, &grid, &x_coords, &y_coords)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(15.5), Some(0.0), Some(21.0), None, None]
+        );
+        Ok(())
+    }
+}
@@ -60,6 +60,10 @@ mod rolling;
 pub mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "merge_sorted")]
+mod sorted_merge;
+#[cfg(feature = "tdigest")]
+mod tdigest_sketch;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
@@ -93,6 +97,8 @@ pub use index::*;
 pub use index_of::*;
 pub use int_range::*;
 #[cfg(feature = "interpolate")]
+pub use interpolation::bilinear::*;
+#[cfg(feature = "interpolate")]
 pub use interpolation::interpolate::*;
 #[cfg(feature = "interpolate_by")]
 pub use interpolation::interpolate_by::*;
@@ -134,6 +140,10 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "merge_sorted")]
+pub use sorted_merge::*;
+#[cfg(feature = "tdigest")]
+pub use tdigest_sketch::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
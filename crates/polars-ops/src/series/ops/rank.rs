@@ -197,6 +197,22 @@ pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
     }
+
+    /// Divide the values into `n` buckets, numbered from 1 to `n`, by their rank.
+    ///
+    /// The value with ordinal rank `r` goes to bucket `floor((r - 1) * n / count) + 1`, where
+    /// `count` is the number of non-null values. Ties are ranked by position. Nulls stay null.
+    fn ntile(&self, n: u32) -> PolarsResult<IdxCa> {
+        polars_ensure!(n > 0, InvalidOperation: "'ntile' requires at least one bucket");
+        let s = self.as_series();
+        let ranks = rank(s, RankMethod::Ordinal, false, None);
+        let ranks = ranks.idx()?;
+        // The values behind nulls are arbitrary, so they must not underflow or divide by zero.
+        let count = ((ranks.len() - ranks.null_count()) as u64).max(1);
+        let out = ranks
+            .apply_values(|r| ((r as u64).saturating_sub(1) * n as u64 / count + 1) as IdxSize);
+        Ok(out.with_name(s.name().clone()))
+    }
 }
 
 impl SeriesRank for Series {}
@@ -310,6 +326,30 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_ntile() -> PolarsResult<()> {
+        let ntile = |values: &[Option<i32>], n| -> PolarsResult<Vec<Option<IdxSize>>> {
+            let s = Series::new("a".into(), values);
+            Ok(Vec::from(&s.ntile(n)?))
+        };
+
+        let sorted = (1..=10).map(Some).collect::<Vec<_>>();
+        let expected = [1, 1, 1, 2, 2, 3, 3, 3, 4, 4].map(Some);
+        assert_eq!(ntile(&sorted, 4)?, expected);
+
+        // The buckets follow the rank of the values, not their position.
+        let values = [Some(30), None, Some(10), Some(20), Some(10)];
+        assert_eq!(
+            ntile(&values, 2)?,
+            &[Some(2), None, Some(1), Some(2), Some(1)]
+        );
+        assert_eq!(ntile(&[Some(1), Some(2)], 5)?, &[Some(1), Some(3)]);
+        assert_eq!(ntile(&[None, None], 5)?, &[None, None]);
+        assert!(ntile(&[], 5)?.is_empty());
+        assert!(ntile(&sorted, 0).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("".into(), &[None, None, None]).into_series();
@@ -5,6 +5,9 @@ use {
     std::ops::SubAssign,
 };
 
+#[cfg(feature = "rank")]
+use super::RankMethod;
+
 #[cfg(feature = "moment")]
 fn rolling_skew_ca<T>(
     ca: &ChunkedArray<T>,
@@ -122,3 +125,419 @@ pub fn rolling_kurtosis(s: &Series, options: RollingOptionsFixedWindow) -> Polar
         dt => polars_bail!(opq = rolling_kurtosis, dt),
     }
 }
+
+/// Counts of the values in a window, indexed by the position of the value among the sorted
+/// distinct values of the whole column.
+///
+/// The counts are kept in Fenwick trees, so that adding or removing a value and counting the
+/// values below a position are all `O(log n)`.
+#[cfg(feature = "rank")]
+struct WindowCounts {
+    counts: Vec<usize>,
+    /// Fenwick tree over `counts`.
+    values: Vec<usize>,
+    /// Fenwick tree over the positions with a non-zero count.
+    distinct: Vec<usize>,
+    len: usize,
+}
+
+#[cfg(feature = "rank")]
+impl WindowCounts {
+    fn new(n_distinct: usize) -> Self {
+        Self {
+            counts: vec![0; n_distinct],
+            values: vec![0; n_distinct + 1],
+            distinct: vec![0; n_distinct + 1],
+            len: 0,
+        }
+    }
+
+    fn update(tree: &mut [usize], pos: usize, insert: bool) {
+        let mut i = pos + 1;
+        while i < tree.len() {
+            if insert {
+                tree[i] += 1;
+            } else {
+                tree[i] -= 1;
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the first `pos` entries of the tree.
+    fn prefix_sum(tree: &[usize], pos: usize) -> usize {
+        let mut i = pos;
+        let mut sum = 0;
+        while i > 0 {
+            sum += tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    fn insert(&mut self, pos: usize) {
+        self.counts[pos] += 1;
+        self.len += 1;
+        Self::update(&mut self.values, pos, true);
+        if self.counts[pos] == 1 {
+            Self::update(&mut self.distinct, pos, true);
+        }
+    }
+
+    fn remove(&mut self, pos: usize) {
+        self.counts[pos] -= 1;
+        self.len -= 1;
+        Self::update(&mut self.values, pos, false);
+        if self.counts[pos] == 0 {
+            Self::update(&mut self.distinct, pos, false);
+        }
+    }
+
+    /// Rank of the value at `pos` in the window, which contains it.
+    fn rank(&self, pos: usize, method: RankMethod) -> f64 {
+        let lt = Self::prefix_sum(&self.values, pos);
+        let le = lt + self.counts[pos];
+        match method {
+            RankMethod::Min => (lt + 1) as f64,
+            // The value is the last one that entered the window, so it is ranked after its ties.
+            RankMethod::Max | RankMethod::Ordinal => le as f64,
+            RankMethod::Average => (lt + 1 + le) as f64 / 2.0,
+            RankMethod::Dense => (Self::prefix_sum(&self.distinct, pos) + 1) as f64,
+            #[cfg(feature = "random")]
+            RankMethod::Random => unreachable!(),
+        }
+    }
+}
+
+/// Compute the rank of every value among the values in its trailing window of
+/// `options.window_size` elements.
+///
+/// The values are first mapped to their position among the sorted distinct values of `ca`. The
+/// window keeps the count of every position in a Fenwick tree, so every step is `O(log n)` and the
+/// total is `O(n * log n)`. Nulls are skipped: a null value has a null rank, and a window with less
+/// than `options.min_periods` non-null values gives a null rank.
+#[cfg(feature = "rank")]
+pub fn rolling_rank(
+    ca: &Float64Chunked,
+    method: RankMethod,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Float64Chunked> {
+    use polars_utils::total_ord::{TotalEq, TotalOrd};
+
+    polars_ensure!(
+        options.window_size > 0,
+        InvalidOperation: "`window_size` must be greater than zero"
+    );
+    polars_ensure!(
+        !options.center && options.weights.is_none(),
+        InvalidOperation: "'rolling_rank' does not support `center` or `weights`"
+    );
+    #[cfg(feature = "random")]
+    polars_ensure!(
+        method != RankMethod::Random,
+        InvalidOperation: "'rolling_rank' does not support the `random` rank method"
+    );
+
+    let ca = ca.rechunk();
+    let mut sorted = ca.iter().flatten().collect::<Vec<_>>();
+    sorted.sort_unstable_by(|a, b| a.tot_cmp(b));
+    sorted.dedup_by(|a, b| a.tot_eq(b));
+    let positions = ca
+        .iter()
+        .map(|v| v.map(|v| sorted.partition_point(|x| x.tot_lt(&v))))
+        .collect::<Vec<_>>();
+    let mut window = WindowCounts::new(sorted.len());
+
+    let out: Float64Chunked = positions
+        .iter()
+        .enumerate()
+        .map(|(i, &pos)| {
+            if i >= options.window_size {
+                if let Some(old) = positions[i - options.window_size] {
+                    window.remove(old);
+                }
+            }
+            let pos = pos?;
+            window.insert(pos);
+
+            (window.len >= options.min_periods).then(|| window.rank(pos, method))
+        })
+        .collect();
+    Ok(out.with_name(ca.name().clone()))
+}
+
+/// Compute the rolling Pearson correlation between `a` and `b`.
+///
+/// The means and the co-moments of the window are updated with Welford's algorithm as values enter
+/// and leave it, so every step is O(1) and large offsets don't cancel out. Only pairs in which
+/// both values are non-null count towards the window; a window with less than
+/// `options.min_periods` (or no more than `ddof`) such pairs gives a null correlation.
+pub fn rolling_corr_series(
+    a: &Series,
+    b: &Series,
+    ddof: u8,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        a.len() == b.len(),
+        length_mismatch = "rolling_corr",
+        a.len(),
+        b.len()
+    );
+    polars_ensure!(
+        options.window_size > 0,
+        InvalidOperation: "`window_size` must be greater than zero"
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "'rolling_corr' does not support `weights`"
+    );
+    for s in [a, b] {
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            opq = rolling_corr,
+            s.dtype()
+        );
+    }
+
+    let a = a.cast(&DataType::Float64)?;
+    let b = b.cast(&DataType::Float64)?;
+    let a = a.f64()?.rechunk();
+    let b = b.f64()?.rechunk();
+    let (a_arr, b_arr) = (a.downcast_as_array(), b.downcast_as_array());
+    let pair = |i: usize| Some((a_arr.get(i)?, b_arr.get(i)?));
+
+    let len = a.len();
+    let window_size = options.window_size;
+    let right_window = window_size.div_ceil(2);
+    let min_periods = options.min_periods.max(ddof as usize + 1);
+
+    let mut state = WelfordCorr::default();
+
+    let (mut start, mut end) = (0, 0);
+    let out: Float64Chunked = (0..len)
+        .map(|i| {
+            let (new_start, new_end) = if options.center {
+                (
+                    i.saturating_sub(window_size - right_window),
+                    len.min(i + right_window),
+                )
+            } else {
+                (i.saturating_sub(window_size - 1), i + 1)
+            };
+            for j in end..new_end {
+                if let Some((x, y)) = pair(j) {
+                    state.insert(x, y);
+                }
+            }
+            for j in start..new_start {
+                if let Some((x, y)) = pair(j) {
+                    state.remove(x, y);
+                }
+            }
+            (start, end) = (new_start, new_end);
+
+            (state.n >= min_periods).then(|| state.corr())
+        })
+        .collect();
+    Ok(out.with_name(a.name().clone()).into_series())
+}
+
+/// The means and co-moments of the pairs in a window.
+#[derive(Default)]
+struct WelfordCorr {
+    n: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+}
+
+impl WelfordCorr {
+    fn insert(&mut self, x: f64, y: f64) {
+        self.n += 1;
+        let n = self.n as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c_xy += dx * (y - self.mean_y);
+    }
+
+    /// Undo [`WelfordCorr::insert`] of a pair that is in the window.
+    fn remove(&mut self, x: f64, y: f64) {
+        self.n -= 1;
+        if self.n == 0 {
+            // Start from scratch instead of carrying rounding errors into the next window.
+            *self = Self::default();
+            return;
+        }
+        let n = self.n as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x -= dx / n;
+        self.mean_y -= dy / n;
+        self.m2_x -= dx * (x - self.mean_x);
+        self.m2_y -= dy * (y - self.mean_y);
+        self.c_xy -= dx * (y - self.mean_y);
+    }
+
+    fn corr(&self) -> f64 {
+        // The `ddof` normalization cancels out in the ratio.
+        (self.c_xy / (self.m2_x * self.m2_y).sqrt()).clamp(-1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_corr() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = Series::new(
+            "b".into(),
+            [Some(2), Some(4), Some(5), None, Some(1), Some(0)],
+        );
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 2,
+            ..Default::default()
+        };
+
+        let out = rolling_corr_series(&a, &b, 1, options)?;
+        let out = out.f64()?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(out.get(0), None);
+        assert!((out.get(1).unwrap() - 1.0).abs() < 1e-12);
+        assert!((out.get(2).unwrap() - 0.9819805060619657).abs() < 1e-12);
+        assert!((out.get(3).unwrap() - 1.0).abs() < 1e-12);
+        assert!((out.get(4).unwrap() + 1.0).abs() < 1e-12);
+        assert!((out.get(5).unwrap() + 1.0).abs() < 1e-12);
+
+        let short = Series::new("b".into(), [1.0]);
+        assert!(rolling_corr_series(&a, &short, 1, Default::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_corr_large_offset() -> PolarsResult<()> {
+        // The correlation doesn't change when a constant is added, so a large offset must give the
+        // same result as no offset at all.
+        let x = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0];
+        let y = [2.0, 7.0, 1.0, 8.0, 2.0, 8.0, 1.0, 8.0, 2.0, 8.0, 4.0, 5.0];
+        let options = RollingOptionsFixedWindow {
+            window_size: 4,
+            ..Default::default()
+        };
+        let expected = rolling_corr_series(
+            &Series::new("a".into(), x),
+            &Series::new("b".into(), y),
+            1,
+            options.clone(),
+        )?;
+
+        let offset = 1e9;
+        let out = rolling_corr_series(
+            &Series::new("a".into(), x.map(|v| v + offset)),
+            &Series::new("b".into(), y.map(|v| v - offset)),
+            1,
+            options,
+        )?;
+        for (out, expected) in out.f64()?.iter().zip(expected.f64()?.iter()) {
+            match (out, expected) {
+                (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-6),
+                (out, expected) => assert_eq!(out, expected),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rank")]
+    fn test_rolling_rank() -> PolarsResult<()> {
+        let ca = Float64Chunked::new(
+            "a".into(),
+            [Some(3.0), Some(1.0), Some(1.0), None, Some(2.0), Some(5.0)],
+        );
+        let options = |min_periods| RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods,
+            ..Default::default()
+        };
+        let rank = |method| -> PolarsResult<Vec<Option<f64>>> {
+            Ok(Vec::from(&rolling_rank(&ca, method, options(1))?))
+        };
+
+        assert_eq!(
+            rank(RankMethod::Min)?,
+            &[Some(1.0), Some(1.0), Some(1.0), None, Some(2.0), Some(2.0)]
+        );
+        assert_eq!(
+            rank(RankMethod::Average)?,
+            &[Some(1.0), Some(1.0), Some(1.5), None, Some(2.0), Some(2.0)]
+        );
+        assert_eq!(
+            rank(RankMethod::Dense)?,
+            &[Some(1.0), Some(1.0), Some(1.0), None, Some(2.0), Some(2.0)]
+        );
+
+        let out = rolling_rank(&ca, RankMethod::Max, options(3))?;
+        assert_eq!(Vec::from(&out), &[None, None, Some(2.0), None, None, None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rank")]
+    fn test_rolling_rank_matches_window_sort() -> PolarsResult<()> {
+        let values = (0..200)
+            .map(|i| (i % 13 != 0).then_some(((i * 7) % 11) as f64))
+            .collect::<Vec<_>>();
+        let ca = Float64Chunked::new("a".into(), &values);
+        let window_size = 6;
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+
+        for method in [
+            RankMethod::Min,
+            RankMethod::Max,
+            RankMethod::Average,
+            RankMethod::Dense,
+        ] {
+            let out = rolling_rank(&ca, method, options.clone())?;
+            for (i, out) in out.iter().enumerate() {
+                let Some(v) = values[i] else {
+                    assert_eq!(out, None);
+                    continue;
+                };
+                let mut window = values[i.saturating_sub(window_size - 1)..=i]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                window.sort_by(f64::total_cmp);
+                let lt = window.iter().filter(|x| **x < v).count();
+                let le = window.iter().filter(|x| **x <= v).count();
+                window.dedup();
+                let distinct_lt = window.iter().filter(|x| **x < v).count();
+                let expected = match method {
+                    RankMethod::Min => lt + 1,
+                    RankMethod::Max => le,
+                    RankMethod::Dense => distinct_lt + 1,
+                    _ => {
+                        assert_eq!(out, Some((lt + 1 + le) as f64 / 2.0));
+                        continue;
+                    },
+                };
+                assert_eq!(out, Some(expected as f64));
+            }
+        }
+        Ok(())
+    }
+}
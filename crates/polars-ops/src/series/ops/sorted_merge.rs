@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
+
+use crate::series::SeriesMethods;
+
+/// Which values to keep when merging two sorted [`Series`] with [`sorted_merge`].
+///
+/// Values are matched pairwise, so a value occurring twice in one input and once in the other
+/// has a single match.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MergeHow {
+    /// Keep the values of both inputs; matched values are kept once.
+    #[default]
+    Union,
+    /// Keep only the values that are in both inputs.
+    Intersection,
+    /// Keep the values of the left input.
+    Left,
+    /// Keep the values of the right input.
+    Right,
+}
+
+fn sorted_merge_ca<T: PolarsNumericType>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    how: MergeHow,
+) -> PolarsResult<ChunkedArray<T>> {
+    let (keep_left, keep_right) = match how {
+        MergeHow::Union => (true, true),
+        MergeHow::Intersection => (false, false),
+        MergeHow::Left => (true, false),
+        MergeHow::Right => (false, true),
+    };
+
+    let left = left.rechunk();
+    let right = right.rechunk();
+    let l = left.cont_slice()?;
+    let r = right.cont_slice()?;
+
+    let mut values = Vec::with_capacity(l.len().max(r.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < l.len() && j < r.len() {
+        match l[i].tot_cmp(&r[j]) {
+            Ordering::Less => {
+                if keep_left {
+                    values.push(l[i]);
+                }
+                i += 1;
+            },
+            Ordering::Greater => {
+                if keep_right {
+                    values.push(r[j]);
+                }
+                j += 1;
+            },
+            Ordering::Equal => {
+                values.push(l[i]);
+                i += 1;
+                j += 1;
+            },
+        }
+    }
+
+    if keep_left {
+        values.extend_from_slice(&l[i..]);
+    }
+    if keep_right {
+        values.extend_from_slice(&r[j..]);
+    }
+    let mut out = ChunkedArray::from_vec(left.name().clone(), values);
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+/// Merge two [`Series`] that are sorted in ascending order in a single `O(n + m)` pass.
+///
+/// The output is sorted as well. Both inputs must have the same numeric or temporal dtype and
+/// must not contain nulls.
+pub fn sorted_merge(left: &Series, right: &Series, how: MergeHow) -> PolarsResult<Series> {
+    polars_ensure!(
+        left.dtype() == right.dtype(),
+        SchemaMismatch: "cannot merge series of dtypes {} and {}", left.dtype(), right.dtype()
+    );
+    polars_ensure!(
+        !left.has_nulls() && !right.has_nulls(),
+        InvalidOperation: "'sorted_merge' does not support null values"
+    );
+    left.ensure_sorted_arg("sorted_merge")?;
+    right.ensure_sorted_arg("sorted_merge")?;
+
+    let l = left.to_physical_repr();
+    let r = right.to_physical_repr();
+    polars_ensure!(
+        l.dtype().is_primitive_numeric(),
+        InvalidOperation: "'sorted_merge' operation not supported for dtype `{}`", left.dtype()
+    );
+
+    let out = with_match_physical_numeric_polars_type!(l.dtype(), |$T| {
+        let l: &ChunkedArray<$T> = l.as_ref().as_ref().as_ref();
+        let r: &ChunkedArray<$T> = r.as_ref().as_ref().as_ref();
+        sorted_merge_ca(l, r, how)?.into_series()
+    });
+    // SAFETY: the physical type is unchanged.
+    unsafe { out.from_physical_unchecked(left.dtype()) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sorted_merge() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [1, 3, 3, 5, 8]);
+        let b = Series::new("b".into(), [2, 3, 5, 9]);
+
+        let merge = |how| -> PolarsResult<Vec<Option<i32>>> {
+            Ok(Vec::from(sorted_merge(&a, &b, how)?.i32()?))
+        };
+        let v = |values: &[i32]| values.iter().copied().map(Some).collect::<Vec<_>>();
+
+        assert_eq!(merge(MergeHow::Union)?, v(&[1, 2, 3, 3, 5, 8, 9]));
+        assert_eq!(merge(MergeHow::Intersection)?, v(&[3, 5]));
+        assert_eq!(merge(MergeHow::Left)?, v(&[1, 3, 3, 5, 8]));
+        assert_eq!(merge(MergeHow::Right)?, v(&[2, 3, 5, 9]));
+
+        let unsorted = Series::new("c".into(), [3, 1]);
+        assert!(sorted_merge(&a, &unsorted, MergeHow::Union).is_err());
+        Ok(())
+    }
+}
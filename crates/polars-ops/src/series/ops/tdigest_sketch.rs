@@ -0,0 +1,63 @@
+use polars_core::prelude::*;
+use polars_utils::pl_serialize;
+use tdigest::TDigest;
+
+fn deserialize(sketch: &[u8]) -> PolarsResult<TDigest> {
+    pl_serialize::deserialize_from_reader::<_, _, false>(sketch)
+        .map_err(|e| e.context("invalid t-digest sketch".into()))
+}
+
+/// Merge serialized T-Digest sketches into a single serialized sketch.
+///
+/// Null sketches are ignored.
+pub fn tdigest_merge(sketches: &BinaryChunked) -> PolarsResult<Vec<u8>> {
+    let digests = sketches
+        .iter()
+        .flatten()
+        .map(deserialize)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    pl_serialize::serialize_to_bytes::<_, false>(&TDigest::merge_digests(digests))
+}
+
+/// Estimate the `q`-th quantile from a serialized T-Digest sketch.
+pub fn tdigest_quantile(sketch: &[u8], q: f64) -> PolarsResult<f64> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&q),
+        ComputeError: "quantile should be between 0.0 and 1.0"
+    );
+    let digest = deserialize(sketch)?;
+    polars_ensure!(
+        !digest.is_empty(),
+        ComputeError: "cannot estimate a quantile from an empty t-digest"
+    );
+    Ok(digest.estimate_quantile(q))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sketch(values: impl IntoIterator<Item = f64>) -> Vec<u8> {
+        let digest = TDigest::new_with_size(100).merge_unsorted(values.into_iter().collect());
+        pl_serialize::serialize_to_bytes::<_, false>(&digest).unwrap()
+    }
+
+    #[test]
+    fn test_tdigest_merge() -> PolarsResult<()> {
+        let a = sketch((1..=500).map(|v| v as f64));
+        let b = sketch((501..=1000).map(|v| v as f64));
+        let sketches =
+            BinaryChunked::from_slice_options("sketch".into(), &[Some(&a[..]), None, Some(&b[..])]);
+
+        let merged = tdigest_merge(&sketches)?;
+        let median = tdigest_quantile(&merged, 0.5)?;
+        assert!((median - 500.0).abs() < 5.0);
+
+        assert!(tdigest_quantile(&merged, 1.5).is_err());
+        assert!(tdigest_quantile(b"not a sketch", 0.5).is_err());
+
+        let empty = tdigest_merge(&BinaryChunked::full_null("sketch".into(), 2))?;
+        assert!(tdigest_quantile(&empty, 0.5).is_err());
+        Ok(())
+    }
+}
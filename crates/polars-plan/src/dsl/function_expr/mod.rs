@@ -167,6 +167,10 @@ pub enum FunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    Ntile {
+        n: u32,
+    },
     Repeat,
     #[cfg(feature = "round_series")]
     Clip {
@@ -215,6 +219,12 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "tdigest")]
+    TDigestMerge,
+    #[cfg(feature = "tdigest")]
+    TDigestQuantile {
+        quantile: f64,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
@@ -516,6 +526,8 @@ impl Hash for FunctionExpr {
                 options.hash(state);
                 seed.hash(state);
             },
+            #[cfg(feature = "rank")]
+            Ntile { n } => n.hash(state),
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 has_min.hash(state);
@@ -549,6 +561,10 @@ impl Hash for FunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => {},
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { quantile } => quantile.to_bits().hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
@@ -722,6 +738,8 @@ impl Display for FunctionExpr {
             Repeat => "repeat",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            Ntile { .. } => "ntile",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -759,6 +777,10 @@ impl Display for FunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => "tdigest_merge",
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { .. } => "tdigest_quantile",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
@@ -785,13 +785,33 @@ impl Expr {
             .expect("We explicitly passed `partition_by`")
     }
 
+    /// Apply window function over a subgroup, sorted by the `order_by` keys within every group.
+    ///
+    /// The `descending` and `nulls_last` flags of the [`SortMultipleOptions`] are given per
+    /// `order_by` key, or once for all keys.
+    ///
+    /// The `order_by` options used to be a single [`SortOptions`]. Existing options convert with
+    /// `SortMultipleOptions::from(&sort_options)`, which applies them to all keys.
     pub fn over_with_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
         self,
         partition_by: Option<E>,
-        order_by: Option<(E, SortOptions)>,
+        order_by: Option<(E, SortMultipleOptions)>,
         options: WindowMapping,
     ) -> PolarsResult<Self> {
         polars_ensure!(partition_by.is_some() || order_by.is_some(), InvalidOperation: "At least one of `partition_by` and `order_by` must be specified in `over`");
+        if let Some((e, options)) = &order_by {
+            let n_by = e.as_ref().len();
+            let n_desc = options.descending.len();
+            polars_ensure!(
+                n_desc == n_by || n_desc == 1,
+                InvalidOperation: "the length of `descending` ({}) does not match the length of `order_by` ({})", n_desc, n_by
+            );
+            let n_nulls_last = options.nulls_last.len();
+            polars_ensure!(
+                n_nulls_last == n_by || n_nulls_last == 1,
+                InvalidOperation: "the length of `nulls_last` ({}) does not match the length of `order_by` ({})", n_nulls_last, n_by
+            );
+        }
         let partition_by = if let Some(partition_by) = partition_by {
             partition_by
                 .as_ref()
@@ -849,6 +869,14 @@ impl Expr {
         self.map_unary(FunctionExpr::FillNullWithStrategy(strategy))
     }
 
+    /// Replace the null values by the first non-null value of the `fallbacks`, from left to right.
+    pub fn coalesce<E: AsRef<[Expr]>>(self, fallbacks: E) -> Self {
+        let mut exprs = Vec::with_capacity(fallbacks.as_ref().len() + 1);
+        exprs.push(self);
+        exprs.extend_from_slice(fallbacks.as_ref());
+        Expr::n_ary(FunctionExpr::Coalesce, exprs)
+    }
+
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
@@ -919,6 +947,22 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Merge a column of serialized T-Digest sketches into a single serialized sketch.
+    ///
+    /// Null sketches are ignored.
+    #[cfg(feature = "tdigest")]
+    pub fn tdigest_merge(self) -> Self {
+        self.map_unary(FunctionExpr::TDigestMerge)
+    }
+
+    /// Estimate the `quantile` from each serialized T-Digest sketch.
+    ///
+    /// Null sketches stay null.
+    #[cfg(feature = "tdigest")]
+    pub fn tdigest_quantile(self, quantile: f64) -> Self {
+        self.map_unary(FunctionExpr::TDigestQuantile { quantile })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1261,6 +1305,17 @@ impl Expr {
         self.map_unary(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Divide the values into `n` buckets by their rank, numbered from 1 to `n`.
+    ///
+    /// The value with ordinal rank `r` among the `count` non-null values goes to bucket
+    /// `floor((r - 1) * n / count) + 1`; nulls stay null. Combine with
+    /// [`Expr::over_with_options`] to bucket within groups, with ties ranked in the `order_by`
+    /// order.
+    pub fn ntile(self, n: u32) -> Expr {
+        self.map_unary(FunctionExpr::Ntile { n })
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Expr {
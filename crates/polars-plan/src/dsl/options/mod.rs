@@ -221,6 +221,8 @@ pub struct UnpivotArgsDSL {
     pub index: Selector,
     pub variable_name: Option<PlSmallStr>,
     pub value_name: Option<PlSmallStr>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub variable_column_prefix: Option<PlSmallStr>,
 }
 
 #[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
@@ -1,4 +1,4 @@
-use polars_core::prelude::SortOptions;
+use polars_core::prelude::SortMultipleOptions;
 use polars_utils::arena::{Arena, Node};
 
 use super::{AExpr, IRAggExpr};
@@ -72,7 +72,7 @@ impl AExpr {
             E::AnonymousFunction { input: l_input, function: l_function, output_type: l_output_type, options: l_options, fmt_str: l_fmt_str } => matches!(other, E::AnonymousFunction { input: r_input, function: r_function, output_type: r_output_type, options: r_options, fmt_str: r_fmt_str } if l_input.len() == r_input.len() && l_function == r_function && l_output_type == r_output_type && l_options == r_options && l_fmt_str == r_fmt_str),
             E::Eval { expr: _, evaluation: _, variant: l_variant } => matches!(other, E::Eval { expr: _, evaluation: _, variant: r_variant } if l_variant == r_variant),
             E::Function { input: l_input, function: l_function, options: l_options } => matches!(other, E::Function { input: r_input, function: r_function, options: r_options } if l_input.len() == r_input.len() && l_function == r_function && l_options == r_options),
-            E::Window { function: _, partition_by: l_partition_by, order_by: l_order_by, options: l_options } => matches!(other, E::Window { function: _, partition_by: r_partition_by, order_by: r_order_by, options: r_options } if l_partition_by.len() == r_partition_by.len() && l_order_by.as_ref().map(|(_, v): &(Node, SortOptions)| v) == r_order_by.as_ref().map(|(_, v): &(Node, SortOptions)| v) && l_options == r_options),
+            E::Window { function: _, partition_by: l_partition_by, order_by: l_order_by, options: l_options } => matches!(other, E::Window { function: _, partition_by: r_partition_by, order_by: r_order_by, options: r_options } if l_partition_by.len() == r_partition_by.len() && l_order_by.as_ref().map(|(_, v): &(Node, SortMultipleOptions)| v) == r_order_by.as_ref().map(|(_, v): &(Node, SortMultipleOptions)| v) && l_options == r_options),
 
             // Discriminant check done above.
             E::Filter { input: _, by: _ } |
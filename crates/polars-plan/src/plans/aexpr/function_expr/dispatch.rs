@@ -13,6 +13,24 @@ pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+#[cfg(feature = "tdigest")]
+pub(super) fn tdigest_merge(s: &Column) -> PolarsResult<Column> {
+    let sketch = polars_ops::prelude::tdigest_merge(s.binary()?)?;
+    Ok(Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Binary, AnyValue::BinaryOwned(sketch)),
+        1,
+    ))
+}
+
+#[cfg(feature = "tdigest")]
+pub(super) fn tdigest_quantile(s: &Column, quantile: f64) -> PolarsResult<Column> {
+    let out: Float64Chunked = s.binary()?.try_apply_nonnull_values_generic(|sketch| {
+        polars_ops::prelude::tdigest_quantile(sketch, quantile)
+    })?;
+    Ok(out.into_column())
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
@@ -225,6 +243,11 @@ pub(super) fn rank(s: &Column, options: RankOptions, seed: Option<u64>) -> Polar
     Ok(s.as_materialized_series().rank(options, seed).into_column())
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn ntile(s: &Column, n: u32) -> PolarsResult<Column> {
+    Ok(s.as_materialized_series().ntile(n)?.into_column())
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Column],
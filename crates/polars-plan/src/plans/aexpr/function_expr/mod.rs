@@ -215,6 +215,10 @@ pub enum IRFunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    Ntile {
+        n: u32,
+    },
     Repeat,
     #[cfg(feature = "round_series")]
     Clip {
@@ -263,6 +267,12 @@ pub enum IRFunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "tdigest")]
+    TDigestMerge,
+    #[cfg(feature = "tdigest")]
+    TDigestQuantile {
+        quantile: f64,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
@@ -571,6 +581,8 @@ impl Hash for IRFunctionExpr {
                 options.hash(state);
                 seed.hash(state);
             },
+            #[cfg(feature = "rank")]
+            Ntile { n } => n.hash(state),
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 has_min.hash(state);
@@ -604,6 +616,10 @@ impl Hash for IRFunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => {},
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { quantile } => quantile.to_bits().hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
@@ -777,6 +793,8 @@ impl Display for IRFunctionExpr {
             Repeat => "repeat",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            Ntile { .. } => "ntile",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -814,6 +832,10 @@ impl Display for IRFunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => "tdigest_merge",
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { .. } => "tdigest_quantile",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
@@ -1161,6 +1183,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Repeat => map_as_slice!(repeat::repeat),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
+            #[cfg(feature = "rank")]
+            Ntile { n } => map!(dispatch::ntile, n),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 map_as_slice!(coerce::as_struct)
@@ -1200,6 +1224,10 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => map!(dispatch::tdigest_merge),
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { quantile } => map!(dispatch::tdigest_quantile, quantile),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
@@ -1467,6 +1495,8 @@ impl IRFunctionExpr {
             F::Product => FunctionOptions::aggregation(),
             #[cfg(feature = "rank")]
             F::Rank { .. } => FunctionOptions::groupwise(),
+            #[cfg(feature = "rank")]
+            F::Ntile { .. } => FunctionOptions::groupwise(),
             F::Repeat => {
                 FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
@@ -1495,6 +1525,10 @@ impl IRFunctionExpr {
             F::UniqueCounts => FunctionOptions::groupwise(),
             #[cfg(feature = "approx_unique")]
             F::ApproxNUnique => FunctionOptions::aggregation(),
+            #[cfg(feature = "tdigest")]
+            F::TDigestMerge => FunctionOptions::aggregation(),
+            #[cfg(feature = "tdigest")]
+            F::TDigestQuantile { .. } => FunctionOptions::elementwise(),
             F::Coalesce => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION)
                 .with_supertyping(Default::default()),
@@ -128,6 +128,8 @@ impl IRFunctionExpr {
                 RankMethod::Average => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
+            #[cfg(feature = "rank")]
+            Ntile { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 let mut field_names = PlHashSet::with_capacity(fields.len() - 1);
@@ -176,6 +178,10 @@ impl IRFunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "tdigest")]
+            TDigestMerge => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "tdigest")]
+            TDigestQuantile { .. } => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
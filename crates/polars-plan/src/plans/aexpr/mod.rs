@@ -219,7 +219,7 @@ pub enum AExpr {
     Window {
         function: Node,
         partition_by: Vec<Node>,
-        order_by: Option<(Node, SortOptions)>,
+        order_by: Option<(Node, SortMultipleOptions)>,
         options: WindowType,
     },
     Slice {
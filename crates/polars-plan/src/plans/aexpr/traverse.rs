@@ -230,8 +230,8 @@ impl AExpr {
                 *function = inputs[0];
                 partition_by.clear();
                 partition_by.extend_from_slice(&inputs[1..inputs.len() - offset]);
-                if let Some((_, options)) = order_by {
-                    *order_by = Some((*inputs.last().unwrap(), *options));
+                if let Some((node, _)) = order_by {
+                    *node = *inputs.last().unwrap();
                 }
                 return self;
             },
@@ -776,6 +776,8 @@ pub(super) fn convert_functions(
         F::Product => I::Product,
         #[cfg(feature = "rank")]
         F::Rank { options, seed } => I::Rank { options, seed },
+        #[cfg(feature = "rank")]
+        F::Ntile { n } => I::Ntile { n },
         F::Repeat => {
             polars_ensure!(&e[0].is_scalar(ctx.arena), ShapeMismatch: "'value' must be a scalar value");
             polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
@@ -816,6 +818,10 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        #[cfg(feature = "tdigest")]
+        F::TDigestMerge => I::TDigestMerge,
+        #[cfg(feature = "tdigest")]
+        F::TDigestQuantile { quantile } => I::TDigestQuantile { quantile },
         F::Coalesce => I::Coalesce,
         F::ShrinkType => I::ShrinkType,
         #[cfg(feature = "diff")]
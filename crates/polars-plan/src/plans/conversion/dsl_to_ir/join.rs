@@ -271,21 +271,33 @@ pub fn resolve_join(
         let ltype = get_dtype!(lnode, &schema_left)?;
         let rtype = get_dtype!(rnode, &schema_right)?;
 
-        if let Some(dtype) = get_numeric_upcast_supertype_lossless(&ltype, &rtype) {
-            // We use overflowing cast to allow better optimization as we are casting to a known
-            // lossless supertype.
-            //
+        // We use overflowing cast to allow better optimization as we are casting to a known
+        // lossless supertype.
+        let upcast = get_numeric_upcast_supertype_lossless(&ltype, &rtype)
+            .map(|dtype| (dtype, CastOptions::Overflowing));
+        // Duration keys with different time units are compared in nanoseconds. Coarser units
+        // can overflow when converted, so these casts are strict.
+        #[cfg(feature = "dtype-duration")]
+        let upcast = upcast.or_else(|| match (&ltype, &rtype) {
+            (DataType::Duration(tu_l), DataType::Duration(tu_r)) if tu_l != tu_r => Some((
+                DataType::Duration(TimeUnit::Nanoseconds),
+                CastOptions::Strict,
+            )),
+            _ => None,
+        });
+
+        if let Some((dtype, options)) = upcast {
             // We have unique references to these nodes (they are created by this function),
             // so we can mutate in-place without causing side effects somewhere else.
             let casted_l = ctxt.expr_arena.add(AExpr::Cast {
                 expr: lnode.node(),
                 dtype: dtype.clone(),
-                options: CastOptions::Overflowing,
+                options,
             });
             let casted_r = ctxt.expr_arena.add(AExpr::Cast {
                 expr: rnode.node(),
                 dtype,
-                options: CastOptions::Overflowing,
+                options,
             });
 
             if key_cols_coalesced {
@@ -422,6 +434,10 @@ fn resolve_join_where(
         ctxt.opt_flags.set(OptFlags::PREDICATE_PUSHDOWN, true);
     }
     ctxt.opt_flags.set(OptFlags::COLLAPSE_JOINS, true);
+    polars_ensure!(
+        matches!(options.args.how, JoinType::Inner),
+        InvalidOperation: "'join_where' only supports inner joins, got '{}'", options.args.how
+    );
     check_join_keys(&predicates)?;
     let input_left = to_alp_impl(Arc::unwrap_or_clone(input_left), ctxt)
         .map_err(|e| e.context(failed_here!(join left)))?;
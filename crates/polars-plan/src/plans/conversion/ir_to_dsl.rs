@@ -851,6 +851,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Product => F::Product,
         #[cfg(feature = "rank")]
         IF::Rank { options, seed } => F::Rank { options, seed },
+        #[cfg(feature = "rank")]
+        IF::Ntile { n } => F::Ntile { n },
         IF::Repeat => F::Repeat,
         #[cfg(feature = "round_series")]
         IF::Clip { has_min, has_max } => F::Clip { has_min, has_max },
@@ -887,6 +889,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         IF::ApproxNUnique => F::ApproxNUnique,
+        #[cfg(feature = "tdigest")]
+        IF::TDigestMerge => F::TDigestMerge,
+        #[cfg(feature = "tdigest")]
+        IF::TDigestQuantile { quantile } => F::TDigestQuantile { quantile },
         IF::Coalesce => F::Coalesce,
         IF::ShrinkType => F::ShrinkType,
         #[cfg(feature = "diff")]
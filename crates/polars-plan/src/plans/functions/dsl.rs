@@ -48,6 +48,8 @@ pub enum DslFunction {
     Stats(StatsFunction),
     /// FillValue
     FillNan(Expr),
+    /// User-defined label that is shown in the plan.
+    Label(PlSmallStr),
     // Function that is already converted to IR.
     #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
     FunctionIR(FunctionIR),
@@ -99,6 +101,7 @@ impl DslFunction {
                     index: index.into_iter().collect(),
                     variable_name: args.variable_name.clone(),
                     value_name: args.value_name,
+                    variable_column_prefix: args.variable_column_prefix,
                 };
 
                 FunctionIR::Unpivot {
@@ -112,6 +115,7 @@ impl DslFunction {
                 offset,
                 schema: Default::default(),
             },
+            DslFunction::Label(label) => FunctionIR::Label(label),
             DslFunction::Unnest(selector) => {
                 let columns = selector.into_columns(input_schema, &Default::default())?;
                 let columns = columns.into_iter().collect();
@@ -48,6 +48,8 @@ pub enum FunctionIR {
         columns: Arc<[PlSmallStr]>,
     },
     Rechunk,
+    /// Passes the data through unchanged; only used to annotate the plan.
+    Label(PlSmallStr),
     Explode {
         columns: Arc<[PlSmallStr]>,
         #[cfg_attr(feature = "ir_serde", serde(skip))]
@@ -80,6 +82,7 @@ impl PartialEq for FunctionIR {
         use FunctionIR::*;
         match (self, other) {
             (Rechunk, Rechunk) => true,
+            (Label(l), Label(r)) => l == r,
             (
                 FastCount {
                     sources: srcs_l, ..
@@ -117,6 +120,7 @@ impl Hash for FunctionIR {
             },
             FunctionIR::Unnest { columns } => columns.hash(state),
             FunctionIR::Rechunk => {},
+            FunctionIR::Label(label) => label.hash(state),
             FunctionIR::Explode { columns, schema: _ } => columns.hash(state),
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { args, schema: _ } => args.hash(state),
@@ -138,7 +142,7 @@ impl FunctionIR {
         use FunctionIR::*;
         match self {
             Rechunk => false,
-            FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            FastCount { .. } | Unnest { .. } | Label(_) | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Opaque { streamable, .. } => *streamable,
@@ -167,7 +171,7 @@ impl FunctionIR {
             OpaquePython(OpaquePythonUdf { predicate_pd, .. }) => *predicate_pd,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            Rechunk | Unnest { .. } | Explode { .. } => true,
+            Rechunk | Unnest { .. } | Label(_) | Explode { .. } => true,
             RowIndex { .. } | FastCount { .. } => false,
         }
     }
@@ -178,7 +182,7 @@ impl FunctionIR {
             Opaque { projection_pd, .. } => *projection_pd,
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { projection_pd, .. }) => *projection_pd,
-            Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            Rechunk | FastCount { .. } | Unnest { .. } | Label(_) | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             RowIndex { .. } => true,
@@ -215,6 +219,7 @@ impl FunctionIR {
                 df.as_single_chunk_par();
                 Ok(df)
             },
+            Label(_) => Ok(df),
             Unnest { columns: _columns } => {
                 feature_gated!("dtype-struct", df.unnest(_columns.iter().cloned()))
             },
@@ -241,6 +246,7 @@ impl Display for FunctionIR {
         use FunctionIR::*;
         match self {
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
+            Label(label) => write!(f, "LABEL: {label}"),
             Unnest { columns } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
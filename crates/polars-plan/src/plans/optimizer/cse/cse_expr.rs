@@ -885,7 +885,9 @@ impl RewritingVisitor for CommonSubExprOptimizer {
     ) -> PolarsResult<RewriteRecursion> {
         use IR::*;
         Ok(match node.to_alp(&arena.0) {
-            Select { .. } | HStack { .. } | GroupBy { .. } => RewriteRecursion::MutateAndContinue,
+            Select { .. } | HStack { .. } | GroupBy { .. } | Filter { .. } => {
+                RewriteRecursion::MutateAndContinue
+            },
             _ => RewriteRecursion::NoMutateAndContinue,
         })
     }
@@ -1032,6 +1034,39 @@ impl RewritingVisitor for CommonSubExprOptimizer {
                     arena.0.replace(arena_idx, lp);
                 }
             },
+            IR::Filter { input, predicate } => {
+                let input = *input;
+                let input_schema = arena.0.get(input).schema(&arena.0).into_owned();
+                if let Some(exprs) = self.find_cse(
+                    std::slice::from_ref(predicate),
+                    &mut arena.1,
+                    &mut id_array_offsets,
+                    false,
+                    input_schema.as_ref(),
+                )? {
+                    // The temporary columns are filtered together with the input, so they must
+                    // have the same length.
+                    if exprs
+                        .cse_exprs()
+                        .iter()
+                        .all(|e| is_elementwise_rec(e.node(), &arena.1))
+                    {
+                        let predicate = exprs.default_exprs()[0].clone();
+
+                        let lp = IRBuilder::new(input, &mut arena.1, &mut arena.0)
+                            .with_columns(exprs.cse_exprs().to_vec(), Default::default())
+                            .build();
+                        let input = arena.0.add(lp);
+                        let input = arena.0.add(IR::Filter { input, predicate });
+
+                        let lp = IR::SimpleProjection {
+                            input,
+                            columns: input_schema,
+                        };
+                        arena.0.replace(arena_idx, lp);
+                    }
+                }
+            },
             _ => {},
         }
 
@@ -393,6 +393,7 @@ impl PyDataFrame {
             index: strings_to_pl_smallstr(index),
             value_name: value_name.map(|s| s.into()),
             variable_name: variable_name.map(|s| s.into()),
+            variable_column_prefix: None,
         };
 
         py.enter_polars_df(|| self.df.unpivot2(args))
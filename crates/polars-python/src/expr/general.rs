@@ -600,12 +600,9 @@ impl PyExpr {
         let order_by = order_by.map(|order_by| {
             (
                 order_by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>(),
-                SortOptions {
-                    descending: order_by_descending,
-                    nulls_last: order_by_nulls_last,
-                    maintain_order: false,
-                    ..Default::default()
-                },
+                SortMultipleOptions::default()
+                    .with_order_descending(order_by_descending)
+                    .with_nulls_last(order_by_nulls_last),
             )
         });
 
@@ -1461,6 +1461,7 @@ impl PyLazyFrame {
             index: index.inner,
             value_name: value_name.map(|s| s.into()),
             variable_name: variable_name.map(|s| s.into()),
+            variable_column_prefix: None,
         };
 
         let ldf = self.ldf.clone();
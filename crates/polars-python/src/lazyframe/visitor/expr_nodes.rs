@@ -404,10 +404,11 @@ pub struct Window {
     partition_by: Vec<usize>,
     #[pyo3(get)]
     order_by: Option<usize>,
+    /// One flag per `order_by` key, or a single flag for all keys. Same for `order_by_nulls_last`.
     #[pyo3(get)]
-    order_by_descending: bool,
+    order_by_descending: Vec<bool>,
     #[pyo3(get)]
-    order_by_nulls_last: bool,
+    order_by_nulls_last: Vec<bool>,
     #[pyo3(get)]
     options: PyObject,
 }
@@ -1214,6 +1215,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     };
                     ("rank", method, options.descending, seed.map(|s| s as i64)).into_py_any(py)
                 },
+                IRFunctionExpr::Ntile { n } => ("ntile", n).into_py_any(py),
                 IRFunctionExpr::Clip { has_min, has_max } => {
                     ("clip", has_min, has_max).into_py_any(py)
                 },
@@ -1234,6 +1236,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                #[cfg(feature = "tdigest")]
+                IRFunctionExpr::TDigestMerge => ("tdigest_merge",).into_py_any(py),
+                #[cfg(feature = "tdigest")]
+                IRFunctionExpr::TDigestQuantile { quantile } => {
+                    ("tdigest_quantile", quantile).into_py_any(py)
+                },
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 IRFunctionExpr::ShrinkType => ("shrink_dtype",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
@@ -1392,12 +1400,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
             let function = function.0;
             let partition_by = partition_by.iter().map(|n| n.0).collect();
             let order_by_descending = order_by
-                .map(|(_, options)| options.descending)
-                .unwrap_or(false);
+                .as_ref()
+                .map(|(_, options)| options.descending.clone())
+                .unwrap_or_default();
             let order_by_nulls_last = order_by
-                .map(|(_, options)| options.nulls_last)
-                .unwrap_or(false);
-            let order_by = order_by.map(|(n, _)| n.0);
+                .as_ref()
+                .map(|(_, options)| options.nulls_last.clone())
+                .unwrap_or_default();
+            let order_by = order_by.as_ref().map(|(n, _)| n.0);
 
             let options = match options {
                 WindowType::Over(options) => PyWindowMapping { inner: *options }.into_py_any(py)?,
@@ -114,6 +114,28 @@ impl PolarsTemporalGroupby for DataFrame {
     }
 }
 
+/// Create a [`GroupBy`] of time-based rolling windows over a sorted `index_column`.
+///
+/// Every row defines a window `(t + offset, t + offset + period)` where `t` is the
+/// value of `index_column` in that row. The window boundaries are found with a
+/// two-pointer scan, so the groups are computed in O(n).
+pub fn rolling<'a>(
+    df: &'a DataFrame,
+    index_column: &str,
+    period: Duration,
+    offset: Duration,
+    closed: ClosedWindow,
+) -> PolarsResult<GroupBy<'a>> {
+    let options = RollingGroupOptions {
+        index_column: index_column.into(),
+        period,
+        offset,
+        closed_window: closed,
+    };
+    let (time_key, groups) = df.rolling(None, &options)?;
+    Ok(GroupBy::new(df, vec![time_key], groups, None))
+}
+
 impl Wrap<&DataFrame> {
     fn rolling(
         &self,
@@ -638,4 +660,46 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rolling_group_by_free_fn() -> PolarsResult<()> {
+        let mut date = StringChunked::new(
+            "dt".into(),
+            [
+                "2020-01-01 13:45:48",
+                "2020-01-01 16:42:13",
+                "2020-01-01 16:45:09",
+                "2020-01-02 18:12:48",
+                "2020-01-03 19:45:32",
+                "2020-01-08 23:16:43",
+            ],
+        )
+        .as_datetime(
+            None,
+            TimeUnit::Milliseconds,
+            false,
+            false,
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+        )?
+        .into_column();
+        date.set_sorted_flag(IsSorted::Ascending);
+        let a = Column::new("a".into(), [3, 7, 5, 9, 2, 1]);
+        let df = DataFrame::new(vec![date.clone(), a.clone()])?;
+
+        let gb = rolling(
+            &df,
+            "dt",
+            Duration::parse("2d"),
+            Duration::parse("-2d"),
+            ClosedWindow::Right,
+        )?;
+        assert_eq!(gb.keys()[0], date);
+
+        let sum = unsafe { a.agg_sum(gb.get_groups()) };
+        let expected = Column::new("".into(), [3, 10, 15, 24, 11, 1]);
+        assert_eq!(sum, expected);
+
+        Ok(())
+    }
 }
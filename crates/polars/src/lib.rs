@@ -183,10 +183,12 @@
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`]
+//!     - `string_distance` - Levenshtein, Damerau-Levenshtein and Jaro string distances
 //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!   These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
+//! * `protobuf` - Decode protobuf varints from binary view arrays.
 //! * Performance related:
 //!     - `nightly` - Several nightly only features such as SIMD and specialization.
 //!     - `performant` - more fast paths, slower compile times.
@@ -249,6 +251,7 @@
 //!         - `list_gather` take sublist by multiple indices
 //!     - `rank` - Ranking algorithms.
 //!     - `moment` - Kurtosis and skew statistics
+//!     - `tdigest` - Merge T-Digest sketches and estimate quantiles from them.
 //!     - `ewma` - Exponential moving average windows
 //!     - `abs` - Get absolute values of [`Series`].
 //!     - `arange` - Range operation on [`Series`].
@@ -33,3 +33,89 @@ fn split_at() {
     assert_eq!(rhs.value(1), "!!!");
     assert_eq!(rhs.value(2), "hello there");
 }
+
+#[test]
+fn parse_utf8_lossy() {
+    let array = BinaryViewArray::from_slice([
+        Some(b"hello".as_slice()),
+        None,
+        Some(b"invalid \xF0\x90\x80 utf8".as_slice()),
+    ]);
+
+    let out = array.parse_utf8_lossy();
+    assert_eq!(
+        out.iter().collect::<Vec<_>>(),
+        [Some("hello"), None, Some("invalid \u{FFFD} utf8")]
+    );
+}
+
+#[test]
+fn regexp_find_all() {
+    let array = BinaryViewArray::from_slice([
+        Some(b"a1b22c333".as_slice()),
+        None,
+        Some(b"no digits".as_slice()),
+    ]);
+
+    let out = array.regexp_find_all(r"\d+").unwrap();
+    assert_eq!(out.len(), 3);
+    assert_eq!(out.offsets().as_slice(), &[0, 3, 3, 3]);
+    assert!(out.is_valid(0));
+    assert!(out.is_null(1));
+    assert!(out.is_valid(2));
+
+    let pairs = out
+        .values()
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    let positions = pairs
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .unwrap();
+    assert_eq!(positions.values().as_slice(), &[1, 2, 3, 5, 6, 9]);
+
+    assert!(array.regexp_find_all("(").is_err());
+}
+
+#[test]
+fn push_fixed_width() {
+    use arrow::array::builder::StaticArrayBuilder;
+
+    let mut builder = BinaryViewArrayBuilder::new(ArrowDataType::BinaryView);
+    builder
+        .push_fixed_width(Some(b"0123456789abcdef".as_slice()))
+        .push_fixed_width(None)
+        .push_fixed_width(Some(b"fedcba9876543210".as_slice()));
+    let array = builder.freeze();
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        [
+            Some(b"0123456789abcdef".as_slice()),
+            None,
+            Some(b"fedcba9876543210".as_slice())
+        ]
+    );
+
+    let mut builder = BinaryViewArrayBuilder::new(ArrowDataType::BinaryView);
+    builder
+        .push_fixed_width_unchecked_inline(Some(*b"abcd"))
+        .push_fixed_width_unchecked_inline::<4>(None)
+        .push_fixed_width_unchecked_inline(Some(*b"wxyz"));
+    let array = builder.freeze();
+    assert_eq!(array.total_bytes_len(), 8);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        [Some(b"abcd".as_slice()), None, Some(b"wxyz".as_slice())]
+    );
+}
+
+#[test]
+#[should_panic(expected = "same width")]
+fn push_fixed_width_different_widths() {
+    let mut builder = BinaryViewArrayBuilder::new(ArrowDataType::BinaryView);
+    builder
+        .push_fixed_width(Some(b"0123456789ab".as_slice()))
+        .push_fixed_width(Some(b"0123456789abc".as_slice()));
+}
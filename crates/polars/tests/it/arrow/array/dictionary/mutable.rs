@@ -167,3 +167,23 @@ fn test_big_dict() {
     }
     assert_eq!(arr.values().len(), n);
 }
+
+#[test]
+fn merge_from() -> PolarsResult<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend(vec![Some("a"), Some("b")])?;
+
+    let mut b = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    b.try_extend(vec![Some("c"), None, Some("a"), Some("c")])?;
+    let b: DictionaryArray<i32> = b.into();
+
+    a.merge_from::<Utf8Array<i32>>(&b)?;
+    assert_eq!(a.values().len(), 3);
+
+    let a: DictionaryArray<i32> = a.into();
+    assert_eq!(
+        a.keys(),
+        &PrimitiveArray::from([Some(0), Some(1), Some(2), None, Some(0), Some(2)])
+    );
+    Ok(())
+}
@@ -87,3 +87,40 @@ fn test_nested_display() {
     let expected = "ListArray[[[1, 2], [3, 4]], [[5, 6, 7], [], [8]], [[9, 10]]]";
     assert_eq!(format!("{nested:?}"), expected);
 }
+
+#[test]
+fn physical_null_count() {
+    let values = PrimitiveArray::<i32>::from([Some(1), None, Some(3), None, None]);
+
+    let dtype = ListArray::<i32>::default_datatype(ArrowDataType::Int32);
+    let array = ListArray::<i32>::new(
+        dtype,
+        vec![0, 2, 2, 3, 5].try_into().unwrap(),
+        Box::new(values),
+        Some([true, false, true, true].into()),
+    );
+
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(array.physical_null_count(), 4);
+    assert_eq!(array.clone().sliced(0, 3).physical_null_count(), 2);
+}
+
+#[test]
+fn element_offsets() {
+    let values = PrimitiveArray::<i32>::from_slice([1, 2, 3, 4, 5]);
+
+    let dtype = ListArray::<i32>::default_datatype(ArrowDataType::Int32);
+    let array = ListArray::<i32>::new(
+        dtype,
+        vec![0, 2, 2, 3, 5].try_into().unwrap(),
+        Box::new(values),
+        None,
+    );
+
+    let offsets = array.element_offsets();
+    assert_eq!(offsets.values().as_slice(), &[0, 2, 2, 3, 5]);
+    assert_eq!(offsets.null_count(), 0);
+
+    let offsets = array.sliced(1, 2).element_offsets();
+    assert_eq!(offsets.values().as_slice(), &[2, 2, 3]);
+}
@@ -12,7 +12,11 @@ mod struct_;
 mod union;
 mod utf8;
 
-use arrow::array::{Array, PrimitiveArray, clone, new_empty_array, new_null_array};
+use arrow::array::builder::{ArrayBuilder, ShareStrategy, make_builder};
+use arrow::array::{
+    Array, OwnedArray, PrimitiveArray, Utf8ViewArray, clone, merge_validity, new_empty_array,
+    new_null_array,
+};
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::{ArrowDataType, ExtensionType, Field, UnionMode};
 use union::union_type;
@@ -167,3 +171,75 @@ fn test_with_validity() {
 struct A {
     array: Box<dyn Array>,
 }
+
+#[test]
+fn test_owned_array() {
+    use arrow::datatypes::ArrowSchema;
+    use polars::prelude::{NamedFrom, OwnedArrayToSeries, Series};
+
+    let array = PrimitiveArray::from_slice([1i32, 2, 3, 4]).boxed();
+    let owned = OwnedArray::new("a".into(), array).sliced(1, 2);
+
+    assert_eq!(owned.name().as_str(), "a");
+    assert_eq!(owned.len(), 2);
+    assert_eq!(owned.dtype(), &ArrowDataType::Int32);
+    assert_eq!(
+        owned.field(),
+        Field::new("a".into(), ArrowDataType::Int32, true)
+    );
+
+    let expected = Series::new("a".into(), [2i32, 3]);
+    let schema = ArrowSchema::from_iter([owned.field()]);
+    assert!(owned.to_series(&schema).unwrap().equals(&expected));
+    assert!(
+        owned
+            .to_series(&ArrowSchema::default())
+            .unwrap()
+            .equals(&expected)
+    );
+    assert!(Series::try_from(owned.clone()).unwrap().equals(&expected));
+
+    let (name, array) = owned.into_inner();
+    assert_eq!(name.as_str(), "a");
+    assert_eq!(
+        array.as_ref(),
+        &PrimitiveArray::from_slice([2i32, 3]) as &dyn Array
+    );
+}
+
+#[test]
+fn test_merge_validity() {
+    let mask = Bitmap::from([true, false, true]);
+
+    let array = PrimitiveArray::from_slice([1i32, 2, 3]);
+    let merged = merge_validity(&array, &mask);
+    assert_eq!(
+        merged.as_ref(),
+        &PrimitiveArray::from([Some(1i32), None, Some(3)]) as &dyn Array
+    );
+
+    let array = Utf8ViewArray::from_slice([None, Some("b"), Some("c")]);
+    let merged = merge_validity(&array, &mask);
+    assert_eq!(
+        merged.as_ref(),
+        &Utf8ViewArray::from_slice([None, None, Some("c")]) as &dyn Array
+    );
+
+    let array = new_null_array(ArrowDataType::Null, 3);
+    assert_eq!(merge_validity(array.as_ref(), &mask).null_count(), 3);
+}
+
+#[test]
+fn test_array_builder_is_empty() {
+    let array = PrimitiveArray::from_slice([1i32, 2, 3]);
+    let mut builder = make_builder(array.dtype());
+    assert!(builder.is_empty());
+
+    builder.extend(&array, ShareStrategy::Always);
+    assert_eq!(builder.len(), 3);
+    assert!(!builder.is_empty());
+
+    let out = builder.freeze_reset();
+    assert_eq!(out.as_ref(), &array as &dyn Array);
+    assert!(builder.is_empty());
+}
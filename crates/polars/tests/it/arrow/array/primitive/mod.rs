@@ -153,3 +153,20 @@ fn into_iter() {
 
     assert!(array.into_iter().rev().eq(rev))
 }
+
+#[test]
+fn zip_chunks() {
+    let lhs = (0..130).map(|i| (i % 7 != 0).then_some(i)).collect::<Int32Array>();
+    let rhs = (0..130).map(Some).collect::<Int32Array>();
+
+    let out = PrimitiveArray::zip_chunks(&lhs, &rhs, |l, r| (l + r) as i64);
+    let expected = (0..130)
+        .map(|i| (i % 7 != 0).then_some(2 * i as i64))
+        .collect::<Int64Array>();
+    assert_eq!(out, expected);
+
+    let rhs = rhs.sliced(1, 128);
+    let lhs = Int32Array::from_vec((0..128).collect());
+    let out = PrimitiveArray::zip_chunks(&lhs, &rhs, |l, r| (r - l) as i64);
+    assert_eq!(out, Int64Array::from_vec(vec![1; 128]));
+}
@@ -326,3 +326,11 @@ fn extend_from_self() {
         MutablePrimitiveArray::from([Some(1), None, Some(1), None])
     );
 }
+
+#[test]
+fn simd_fill() {
+    let mut a = MutablePrimitiveArray::from([Some(1), None, Some(3)]);
+    a.simd_fill(7);
+    assert_eq!(a, MutablePrimitiveArray::from([Some(7), Some(7), Some(7)]));
+    assert!(a.validity().is_none());
+}
@@ -42,3 +42,15 @@ fn split_at() {
         "StructArray[{b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn field_names() {
+    let array = array();
+
+    assert_eq!(array.field_names(), vec!["b", "c"]);
+    assert_eq!(
+        array.field_by_name("c").unwrap().as_ref(),
+        &Int32Array::from_slice([42, 28, 19, 31]) as &dyn Array
+    );
+    assert!(array.field_by_name("d").is_none());
+}
@@ -76,3 +76,15 @@ fn debug() {
         "Bitmap { len: 7, offset: 2, bytes: [0b111110__, 0b_______1] }"
     );
 }
+
+#[test]
+fn count_zeros_range() {
+    let b = Bitmap::from_iter((0..200).map(|i| i % 3 == 0));
+    for (start, end) in [(0, 0), (0, 200), (5, 7), (3, 131), (64, 128), (70, 199)] {
+        let expected = (start..end).filter(|i| i % 3 != 0).count();
+        assert_eq!(b.count_zeros_range(start, end), expected);
+    }
+
+    let b = b.sliced(1, 100);
+    assert_eq!(b.count_zeros_range(0, 3), 2);
+}
@@ -3,6 +3,7 @@ mod bitmap_ops;
 mod immutable;
 mod mutable;
 mod utils;
+mod validity_shape;
 
 use arrow::array::Splitable;
 use arrow::bitmap::Bitmap;
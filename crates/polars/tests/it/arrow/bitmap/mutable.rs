@@ -440,3 +440,33 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+#[test]
+fn set_range() {
+    let mut a = MutableBitmap::from_len_zeroed(200);
+    a.set_range(3, 150, true);
+    assert_eq!(a.unset_bits(), 200 - 147);
+    assert!(!a.get(2) && a.get(3) && a.get(149) && !a.get(150));
+
+    a.set_range(60, 140, false);
+    assert_eq!(a.set_bits(), 57 + 10);
+
+    // Ranges within a word don't use the word-aligned path.
+    a.set_range(5, 10, false);
+    assert_eq!(a.set_bits(), 52 + 10);
+}
+
+#[test]
+fn set_range_word_aligned() {
+    let mut a = MutableBitmap::from_len_zeroed(256);
+    unsafe { a.set_range_word_aligned(64, 192, true) };
+    assert_eq!(a.set_bits(), 128);
+    assert!(!a.get(63) && a.get(64) && a.get(191) && !a.get(192));
+}
+
+#[test]
+#[should_panic]
+fn set_range_word_aligned_unaligned() {
+    let mut a = MutableBitmap::from_len_zeroed(256);
+    unsafe { a.set_range_word_aligned(8, 64, true) };
+}
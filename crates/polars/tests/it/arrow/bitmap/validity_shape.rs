@@ -0,0 +1,110 @@
+use arrow::array::{Array, PrimitiveArray, Splitable};
+use arrow::bitmap::{Bitmap, ValidityShape};
+use arrow::datatypes::ArrowDataType;
+
+#[test]
+fn from_validity() {
+    assert_eq!(ValidityShape::from_validity(None), ValidityShape::AllValid);
+
+    let all_valid = Bitmap::new_with_value(true, 5);
+    assert_eq!(
+        ValidityShape::from_validity(Some(&all_valid)),
+        ValidityShape::AllValid
+    );
+
+    let prefix = Bitmap::from([false, false, true, true, true]);
+    assert_eq!(
+        ValidityShape::from_validity(Some(&prefix)),
+        ValidityShape::NullPrefix { n: 2 }
+    );
+
+    let arbitrary = Bitmap::from([true, false, true]);
+    assert_eq!(
+        ValidityShape::from_validity(Some(&arbitrary)),
+        ValidityShape::ArbitraryNulls
+    );
+}
+
+#[test]
+fn null_prefix_ops() {
+    let shape = ValidityShape::NullPrefix { n: 2 };
+    assert_eq!(shape.null_count(5), Some(2));
+    assert_eq!(shape.is_valid(1), Some(false));
+    assert_eq!(shape.is_valid(2), Some(true));
+    assert_eq!(shape.sliced(1, 3), ValidityShape::NullPrefix { n: 1 });
+    assert_eq!(shape.sliced(2, 3), ValidityShape::AllValid);
+    assert_eq!(
+        shape.into_validity(5),
+        Some(Bitmap::from([false, false, true, true, true]))
+    );
+
+    let shape = ValidityShape::ArbitraryNulls;
+    assert_eq!(shape.null_count(4), None);
+    assert_eq!(shape.is_valid(0), None);
+    assert_eq!(shape.sliced(1, 3), ValidityShape::ArbitraryNulls);
+}
+
+#[test]
+fn from_validity_lazy() {
+    assert_eq!(
+        ValidityShape::from_validity_lazy(None),
+        ValidityShape::AllValid
+    );
+    assert_eq!(
+        ValidityShape::from_validity_lazy(Some(&Bitmap::new_zeroed(3))),
+        ValidityShape::NullPrefix { n: 3 }
+    );
+    assert_eq!(
+        ValidityShape::from_validity_lazy(Some(&Bitmap::new_with_value(true, 3))),
+        ValidityShape::AllValid
+    );
+}
+
+#[test]
+fn primitive_array_validity_shape() {
+    let array = PrimitiveArray::<i32>::from_slice([1, 2, 3]);
+    assert_eq!(array.validity_shape(), &ValidityShape::AllValid);
+
+    let array = PrimitiveArray::<i32>::from([Some(1), None, Some(2)]);
+    assert_eq!(array.validity_shape(), &ValidityShape::ArbitraryNulls);
+    assert_eq!(array.null_count(), 1);
+
+    let array = PrimitiveArray::<i32>::new_null(ArrowDataType::Int32, 4);
+    assert_eq!(array.validity_shape(), &ValidityShape::NullPrefix { n: 4 });
+    let array = array.sliced(1, 2);
+    assert_eq!(array.validity_shape(), &ValidityShape::NullPrefix { n: 2 });
+    assert_eq!(array.null_count(), 2);
+}
+
+#[test]
+fn primitive_array_null_prefix() {
+    let array = PrimitiveArray::<i32>::from_slice([1, 2, 3, 4, 5])
+        .with_validity_shape(ValidityShape::NullPrefix { n: 2 });
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from([false, false, true, true, true]))
+    );
+    assert_eq!(array.null_count(), 2);
+    assert!(array.is_null(1));
+    assert!(array.is_valid(2));
+
+    let sliced = array.clone().sliced(1, 3);
+    assert_eq!(sliced.validity_shape(), &ValidityShape::NullPrefix { n: 1 });
+    assert_eq!(sliced.null_count(), 1);
+    assert_eq!(
+        sliced.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+        [None, Some(3), Some(4)]
+    );
+
+    let sliced = array.clone().sliced(2, 3);
+    assert_eq!(sliced.validity_shape(), &ValidityShape::AllValid);
+    assert_eq!(sliced.validity(), None);
+
+    let (lhs, rhs) = array.split_at(3);
+    assert_eq!(lhs.validity_shape(), &ValidityShape::NullPrefix { n: 2 });
+    assert_eq!(rhs.validity_shape(), &ValidityShape::AllValid);
+    assert_eq!(rhs.null_count(), 0);
+
+    let array = array.with_validity(None);
+    assert_eq!(array.validity_shape(), &ValidityShape::AllValid);
+}
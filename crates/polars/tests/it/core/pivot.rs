@@ -271,3 +271,64 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_list_values() -> PolarsResult<()> {
+    let values = Series::new(
+        "values".into(),
+        [
+            Some(Series::new("".into(), [Some(1), None])),
+            Some(Series::new("".into(), [Some(2)])),
+            None,
+            Some(Series::new("".into(), [None, Some(3)])),
+            None,
+        ],
+    );
+    let df = DataFrame::new(vec![
+        Column::new("index".into(), ["a", "a", "a", "b", "b"]),
+        Column::new("columns".into(), ["x", "x", "y", "x", "y"]),
+        values.into_column(),
+    ])?;
+
+    let out = pivot_stable(
+        &df,
+        ["columns"],
+        Some(["index"]),
+        Some(["values"]),
+        false,
+        None,
+        None,
+    )?;
+
+    let x = out.column("x")?.list()?;
+    assert_eq!(
+        Vec::from(x.get_as_series(0).unwrap().i32()?),
+        &[Some(1), None, Some(2)]
+    );
+    assert_eq!(
+        Vec::from(x.get_as_series(1).unwrap().i32()?),
+        &[None, Some(3)]
+    );
+
+    let y = out.column("y")?.list()?;
+    assert_eq!(y.null_count(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_pivot_counts() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["a", "a", "b", "a"],
+        "on" => ["x", "y", "x", "x"],
+    ]?;
+
+    let out = polars_ops::pivot::pivot_counts(&df, ["on"], ["index"])?;
+    let expected = df![
+        "index" => ["a", "b"],
+        "x" => [2 as IdxSize, 1],
+        "y" => [1 as IdxSize, 0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -1405,3 +1405,35 @@ fn test_read_io_reader() {
         .head(Some(df.height()));
     assert_eq!(&df, &expected);
 }
+
+#[test]
+fn test_batched_record_batch_reader() -> PolarsResult<()> {
+    use arrow::record_batch::RecordBatchReader;
+    use polars::io::mmap::MmapBytesReader;
+
+    let file = Box::new(std::fs::File::open(FOODS_CSV)?) as Box<dyn MmapBytesReader>;
+    let reader = CsvReadOptions::default()
+        .with_chunk_size(5)
+        .with_row_index(Some(RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        }))
+        .into_reader_with_file_handle(file)
+        .batched(None)?;
+    let schema = reader.schema();
+    let batches = reader.collect::<PolarsResult<Vec<_>>>()?;
+    assert!(batches.len() > 1);
+    assert!(batches.iter().all(|b| b.schema() == schema.as_ref()));
+
+    let dfs = batches.into_iter().map(DataFrame::from).collect::<Vec<_>>();
+    let out = concat_df(&dfs)?;
+    let expected = CsvReadOptions::default()
+        .with_row_index(Some(RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        }))
+        .try_into_reader_with_file_path(Some(FOODS_CSV.into()))?
+        .finish()?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -29,6 +29,34 @@ fn read_json() {
     assert_eq!("d", df.get_columns()[3].name().as_str());
     assert_eq!((12, 4), df.shape());
 }
+
+#[test]
+fn read_jsonl_chunked() -> PolarsResult<()> {
+    let data = r#"{"a":1, "b":"x"}
+{"a":2, "b":"y"}
+
+{"a":3, "b":null}
+{"a":4, "b":"z"}
+{"a":5, "b":"w"}
+"#;
+    let mut reader =
+        JsonlChunkReader::new(Cursor::new(data), None, 2).infer_schema_len(NonZeroUsize::new(3));
+
+    let mut heights = Vec::new();
+    let mut a = Vec::new();
+    while let Some(df) = reader.next_chunk()? {
+        heights.push(df.height());
+        a.extend(df.column("a")?.i64()?.into_no_null_iter());
+    }
+    assert_eq!(heights, [2, 2, 1]);
+    assert_eq!(a, [1, 2, 3, 4, 5]);
+    assert_eq!(reader.schema().unwrap().len(), 2);
+
+    let mut reader = JsonlChunkReader::new(Cursor::new(""), None, 2);
+    assert!(reader.next_chunk()?.is_none());
+    Ok(())
+}
+
 #[test]
 fn read_json_with_whitespace() {
     let basic_json = r#"{   "a":1, "b":2.0, "c"   :false  , "d":"4"}
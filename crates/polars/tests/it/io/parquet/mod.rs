@@ -206,3 +206,112 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_batched_record_batch_reader() -> PolarsResult<()> {
+    use ::arrow::record_batch::RecordBatchReader;
+    use polars::io::RowIndex;
+
+    let mut df = df! {
+        "a" => (0..10).collect::<Vec<i32>>(),
+        "b" => (0..10).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(4))
+        .finish(&mut df)?;
+
+    let row_index = Some(RowIndex {
+        name: "idx".into(),
+        offset: 0,
+    });
+    let mut reader =
+        ParquetReader::new(Cursor::new(buf.get_ref().clone())).with_row_index(row_index.clone());
+    let row_group_heights = reader
+        .get_metadata()?
+        .row_groups
+        .iter()
+        .map(|rg| rg.num_rows())
+        .collect::<Vec<_>>();
+    assert!(row_group_heights.len() > 1);
+    let reader = reader.batched()?;
+    let schema = reader.schema();
+    let batches = reader.collect::<PolarsResult<Vec<_>>>()?;
+
+    assert_eq!(
+        batches.iter().map(|b| b.height()).collect::<Vec<_>>(),
+        row_group_heights
+    );
+    assert!(batches.iter().all(|b| b.schema() == schema.as_ref()));
+
+    let dfs = batches.into_iter().map(DataFrame::from).collect::<Vec<_>>();
+    let out = polars_core::utils::concat_df(&dfs)?;
+    let expected = ParquetReader::new(buf).with_row_index(row_index).finish()?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_batched_reader_with_inline_bytes() -> PolarsResult<()> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    use polars::io::mmap::MmapBytesReader;
+
+    // Keeps the file bytes inline like a `Cursor<[u8; N]>`, so they move together with the
+    // reader.
+    struct InlineBytes<const N: usize> {
+        cursor: Cursor<[u8; N]>,
+        len: usize,
+    }
+
+    impl<const N: usize> Read for InlineBytes<N> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let pos = (self.cursor.position() as usize).min(self.len);
+            let n = (&self.cursor.get_ref()[pos..self.len]).read(buf)?;
+            self.cursor.set_position((pos + n) as u64);
+            Ok(n)
+        }
+    }
+
+    impl<const N: usize> Seek for InlineBytes<N> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            let pos = match pos {
+                SeekFrom::End(offset) => SeekFrom::Start((self.len as i64 + offset) as u64),
+                pos => pos,
+            };
+            self.cursor.seek(pos)
+        }
+    }
+
+    impl<const N: usize> MmapBytesReader for InlineBytes<N> {
+        fn to_bytes(&self) -> Option<&[u8]> {
+            Some(&self.cursor.get_ref()[..self.len])
+        }
+    }
+
+    let mut df = df! {
+        "a" => (0..10).collect::<Vec<i32>>(),
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(4))
+        .finish(&mut df)?;
+    let bytes = buf.into_inner();
+
+    let mut inline = [0u8; 4096];
+    assert!(bytes.len() <= inline.len());
+    inline[..bytes.len()].copy_from_slice(&bytes);
+    let reader = InlineBytes {
+        cursor: Cursor::new(inline),
+        len: bytes.len(),
+    };
+
+    let mut batched = ParquetReader::new(reader).batched()?;
+    let mut dfs = vec![];
+    while let Some(df) = batched.next_batch()? {
+        dfs.push(df);
+    }
+    let out = polars_core::utils::concat_df(&dfs)?;
+    assert!(out.equals(&df));
+    Ok(())
+}
@@ -56,3 +56,75 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "dtype-duration"))]
+fn join_duration_different_time_units() -> PolarsResult<()> {
+    let left = df! {
+        "d" => [1i64, 2, 3],
+        "a" => ["x", "y", "z"],
+    }?
+    .lazy()
+    .select([
+        col("d").cast(DataType::Duration(TimeUnit::Milliseconds)),
+        col("a"),
+    ])
+    .collect()?;
+    let right = df! {
+        "d" => [2_000_000i64, 3_000_000, 4_000_000],
+        "b" => [20, 30, 40],
+    }?
+    .lazy()
+    .select([
+        col("d").cast(DataType::Duration(TimeUnit::Nanoseconds)),
+        col("b"),
+    ])
+    .collect()?;
+
+    let out = left
+        .join(&right, ["d"], ["d"], JoinArgs::new(JoinType::Inner), None)?
+        .sort(["d"], Default::default())?;
+    assert_eq!(Vec::from(out.column("a")?.str()?), &[Some("y"), Some("z")]);
+    assert_eq!(out.column("b")?.i32()?.to_vec(), &[Some(20), Some(30)]);
+    assert_eq!(
+        out.column("d")?.dtype(),
+        &DataType::Duration(TimeUnit::Milliseconds)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "dtype-duration"))]
+fn lazy_join_duration_different_time_units() -> PolarsResult<()> {
+    let left = df! {
+        "d" => [1i64, 2, 3],
+        "a" => ["x", "y", "z"],
+    }?
+    .lazy()
+    .select([
+        col("d").cast(DataType::Duration(TimeUnit::Milliseconds)),
+        col("a"),
+    ]);
+    let right = df! {
+        "d" => [2_000_000i64, 3_000_000, 4_000_000],
+        "b" => [20, 30, 40],
+    }?
+    .lazy()
+    .select([
+        col("d").cast(DataType::Duration(TimeUnit::Nanoseconds)),
+        col("b"),
+    ]);
+
+    let out = left
+        .join(
+            right,
+            [col("d")],
+            [col("d")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["d"], Default::default())
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.str()?), &[Some("y"), Some("z")]);
+    assert_eq!(out.column("b")?.i32()?.to_vec(), &[Some(20), Some(30)]);
+    Ok(())
+}
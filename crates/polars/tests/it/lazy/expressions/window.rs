@@ -393,3 +393,62 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_window_order_by_multiple() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "t" => [1, 1, 0, 5, 5],
+        "s" => [2, 1, 0, 1, 0],
+        "x" => [10, 20, 30, 40, 50]
+    ]?;
+
+    // Ties in `t` are broken by `s`.
+    let out = df
+        .lazy()
+        .select([col("x").shift(lit(1)).over_with_options(
+            Some(vec![col("g")]),
+            Some((vec![col("t"), col("s")], SortMultipleOptions::default())),
+            WindowMapping::GroupsToRows,
+        )?])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(20), Some(30), None, Some(50), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_window_order_by_mixed_directions() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "t" => [1, 1, 0, 5, 5],
+        "s" => [2, 1, 0, 1, 0],
+        "x" => [10, 20, 30, 40, 50]
+    ]?;
+
+    // Ascending in `t`, ties are broken by descending `s`.
+    let over = |descending: Vec<bool>| {
+        col("x").shift(lit(1)).over_with_options(
+            Some(vec![col("g")]),
+            Some((
+                vec![col("t"), col("s")],
+                SortMultipleOptions::default().with_order_descending_multi(descending),
+            )),
+            WindowMapping::GroupsToRows,
+        )
+    };
+    let out = df.lazy().select([over(vec![false, true])?]).collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(30), Some(10), None, None, Some(40)]
+    );
+
+    assert!(over(vec![false, true, false]).is_err());
+    Ok(())
+}